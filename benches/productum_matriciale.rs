@@ -0,0 +1,51 @@
+use minitensor::Tensor2D;
+use std::time::{Duration, Instant};
+
+/// Mensura tempus medium functionis per plures iterationes.
+/// Mesure le temps moyen d’une fonction sur plusieurs itérations.
+fn mensura<F: FnMut() -> Tensor2D>(iterationes: u32, mut f: F) -> Duration {
+    // Una iteratio ad calefaciendum (cache, allocator).
+    // Une itération d’échauffement (cache, allocateur).
+    std::hint::black_box(f());
+
+    let initium = Instant::now();
+    for _ in 0..iterationes {
+        std::hint::black_box(f());
+    }
+    initium.elapsed() / iterationes
+}
+
+fn main() {
+    println!(
+        "Productum matriciale (parallel: {})",
+        if cfg!(feature = "parallel") { "sic" } else { "non" }
+    );
+    println!("{:>6} {:>14} {:>14} {:>10}", "n", "simplex", "tegulatum", "acceleratio");
+
+    for &n in &[64, 128, 256, 512, 768] {
+        let a = Tensor2D::fortuitus(n, n);
+        let b = Tensor2D::fortuitus(n, n);
+        let iterationes = if n <= 128 { 20 } else { 3 };
+
+        // Eadem inputa pro utraque methodo; resultata etiam comparantur.
+        // Mêmes entrées pour les deux méthodes ; les résultats sont aussi comparés.
+        let simplex = a.productum_matriciale_simplex(&b);
+        let tegulatum = a.productum_matriciale(&b);
+        let differentia = simplex.elementa.iter()
+            .zip(&tegulatum.elementa)
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f64::max);
+        assert!(differentia < 1e-9, "Resultata differunt: {}", differentia);
+
+        let tempus_simplex = mensura(iterationes, || a.productum_matriciale_simplex(&b));
+        let tempus_tegulatum = mensura(iterationes, || a.productum_matriciale(&b));
+
+        println!(
+            "{:>6} {:>14.3?} {:>14.3?} {:>9.2}x",
+            n,
+            tempus_simplex,
+            tempus_tegulatum,
+            tempus_simplex.as_secs_f64() / tempus_tegulatum.as_secs_f64()
+        );
+    }
+}
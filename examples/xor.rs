@@ -14,8 +14,8 @@ fn main() {
         (vec![1.0, 1.0], vec![0.0]),
     ];
 
-    // Crea Neuronatum cum 2 inputis, 2 occultis, 1 outputo
-    let mut rete = Neuronatus::novus(2, 2, 1, 0.5);
+    // Crea Neuronatum cum 2 inputis, 2 occultis, 1 outputo; semen fixum cursus comparabiles reddit
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 2, 1], 0.5, 2);
 
     // Itera mille vicibus
     for epochon in 0..10000 {
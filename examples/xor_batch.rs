@@ -19,7 +19,7 @@ fn main() {
     let intratae = Tensor3D::ex_vec(exempla_inputa);
     let exspectatae = Tensor3D::ex_vec(exempla_exitus);
 
-    let mut rete = Neuronatus::novus(2, 2, 1, 0.3);
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 2, 1], 0.3, 42);
 
     for epoch in 0..5000 {
         rete.instruere_batch(&intratae, &exspectatae);
@@ -31,13 +31,13 @@ fn main() {
 
     println!("\nPraedictio finalis:");
     for i in 0..intratae.profunditas {
-        let inputum = Tensor1D::ex_vec(intratae.materia[i][0].clone());
+        let inputum = Tensor1D::ex_vec(intratae.ordo(i, 0).to_vec());
         let exitus = rete.praedictio(&inputum);
         println!(
             "Inputum: {:?} → Exitus: {:.4} | Exspectatum: {}",
             inputum.materia,
             exitus.materia[0],
-            exspectatae.materia[i][0][0]
+            exspectatae[(i, 0, 0)]
         );
     }
 }
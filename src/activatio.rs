@@ -1,13 +1,188 @@
+use minitensor::Numerus;
+use serde::{Serialize, Deserialize};
+
 /// Sigmoide: functio activationis.
 /// Sigmoïde : fonction d’activation.
-pub fn sigmoide(x: f64) -> f64 {
-    1.0 / (1.0 + (-x).exp())
+pub fn sigmoide<T: Numerus>(x: T) -> T {
+    T::UNUS / (T::UNUS + (-x).exp())
 }
 
 /// Derivata sigmoidis.
 /// Dérivée de la sigmoïde.
-pub fn derivata_sigmoidis(x: f64) -> f64 {
+pub fn derivata_sigmoidis<T: Numerus>(x: T) -> T {
     let s = sigmoide(x);
-    s * (1.0 - s)
+    s * (T::UNUS - s)
+}
+
+/// Softmax stabilis: maximum subtrahitur ante exponentiationem.
+/// Softmax numériquement stable : le maximum est soustrait avant l’exponentielle.
+pub fn softmax<T: Numerus>(x: &[T]) -> Vec<T> {
+    let maximum = x.iter().copied().fold(-T::INFINITUS, T::max);
+    let exponentes: Vec<T> = x.iter().map(|&v| (v - maximum).exp()).collect();
+    let summa: T = exponentes.iter().sum();
+    exponentes.iter().map(|&e| e / summa).collect()
+}
+
+/// Constans GELU: √(2/π).
+/// Constante de GELU : √(2/π).
+const RADIX_DUO_PER_PI: f64 = 0.797_884_560_802_865_4;
+
+/// Coefficiens termini cubici in approximatione GELU.
+/// Coefficient du terme cubique dans l’approximation de GELU.
+const GELU_COEFFICIENS: f64 = 0.044715;
+
+/// Functio activationis eligibilis per stratum.
+/// Fonction d’activation sélectionnable pour chaque couche.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Activatio {
+    /// 1 / (1 + e^-x)
+/// Sigmoïde : 1 / (1 + e^-x).
+    #[default]
+    Sigmoides,
+    /// max(0, x)
+/// ReLU : max(0, x).
+    Relu,
+    /// x si x > 0, alioquin alpha·x.
+/// x si x > 0, sinon alpha·x.
+    ReluRimosa { alpha: f64 },
+    /// Tangens hyperbolica.
+/// Tangente hyperbolique.
+    Tanh,
+    /// x si x > 0, alioquin alpha·(e^x − 1).
+/// x si x > 0, sinon alpha·(e^x − 1).
+    Elu { alpha: f64 },
+    /// Gaussian Error Linear Unit (approximatio per tanh).
+/// Gaussian Error Linear Unit (approximation par tanh).
+    Gelu,
+    /// ln(1 + e^x)
+/// Softplus : ln(1 + e^x).
+    Softplus,
+    /// f(x) = x
+/// Identité : f(x) = x.
+    Identitas,
+    /// Distributio probabilitatis per totum stratum.
+/// Distribution de probabilité sur toute la couche.
+    Softmax,
 }
 
+impl Activatio {
+    /// Applica functionem ad vectorem summarum.
+/// Applique la fonction à un vecteur de sommes pondérées.
+    pub fn applica<T: Numerus>(&self, x: &[T]) -> Vec<T> {
+        match self {
+            Activatio::Softmax => softmax(x),
+            _ => x.iter().map(|&v| self.applica_scalarem(v)).collect(),
+        }
+    }
+
+    /// Derivata elementaris f'(x); pro softmax redditur diagonalis Iacobianae.
+/// Dérivée élément par élément ; pour softmax, la diagonale de la jacobienne.
+    pub fn derivata<T: Numerus>(&self, x: &[T]) -> Vec<T> {
+        match self {
+            Activatio::Softmax => softmax(x).iter().map(|&s| s * (T::UNUS - s)).collect(),
+            _ => x.iter().map(|&v| self.derivata_scalaris(v)).collect(),
+        }
+    }
+
+    /// Retropropagatio: productum gradientis cum Iacobiana functionis in x.
+/// Rétropropagation : produit du gradient par la jacobienne de la fonction en x.
+    pub fn retro<T: Numerus>(&self, x: &[T], gradiens: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), gradiens.len());
+
+        match self {
+            // J = diag(s) − s·sᵗ, ergo (Jᵗg)_i = s_i · (g_i − Σ_j s_j·g_j).
+            // J = diag(s) − s·sᵗ, donc (Jᵗg)_i = s_i · (g_i − Σ_j s_j·g_j).
+            Activatio::Softmax => {
+                let s = softmax(x);
+                let productum: T = s.iter().zip(gradiens).map(|(&s, &g)| s * g).sum();
+                s.iter().zip(gradiens).map(|(&s, &g)| s * (g - productum)).collect()
+            }
+            _ => x.iter()
+                .zip(gradiens)
+                .map(|(&v, &g)| self.derivata_scalaris(v) * g)
+                .collect(),
+        }
+    }
+
+    /// Valor functionis elementaris in x.
+/// Valeur de la fonction élémentaire en x.
+    fn applica_scalarem<T: Numerus>(&self, x: T) -> T {
+        let nullus = T::NULLUS;
+        match *self {
+            Activatio::Sigmoides => sigmoide(x),
+            Activatio::Relu => x.max(nullus),
+            Activatio::ReluRimosa { alpha } => if x > nullus { x } else { T::ex_f64(alpha) * x },
+            Activatio::Tanh => x.tanh(),
+            Activatio::Elu { alpha } => if x > nullus { x } else { T::ex_f64(alpha) * x.exp_m1() },
+            Activatio::Gelu => {
+                let (dimidium, c) = (T::ex_f64(0.5), T::ex_f64(GELU_COEFFICIENS));
+                dimidium * x * (T::UNUS + (T::ex_f64(RADIX_DUO_PER_PI) * (x + c * x.powi(3))).tanh())
+            }
+            // ln(1 + e^x) = max(x, 0) + ln(1 + e^-|x|), sine exundatione.
+            Activatio::Softplus => x.max(nullus) + (-x.abs()).exp().ln_1p(),
+            Activatio::Identitas => x,
+            Activatio::Softmax => unreachable!("Softmax per totum vectorem computatur."),
+        }
+    }
+
+    /// Derivata functionis elementaris in x.
+/// Dérivée de la fonction élémentaire en x.
+    fn derivata_scalaris<T: Numerus>(&self, x: T) -> T {
+        let (nullus, unus) = (T::NULLUS, T::UNUS);
+        match *self {
+            Activatio::Sigmoides => derivata_sigmoidis(x),
+            Activatio::Relu => if x > nullus { unus } else { nullus },
+            Activatio::ReluRimosa { alpha } => if x > nullus { unus } else { T::ex_f64(alpha) },
+            Activatio::Tanh => unus - x.tanh().powi(2),
+            Activatio::Elu { alpha } => if x > nullus { unus } else { T::ex_f64(alpha) * x.exp() },
+            Activatio::Gelu => {
+                let (dimidium, c, radix) = (T::ex_f64(0.5), T::ex_f64(GELU_COEFFICIENS), T::ex_f64(RADIX_DUO_PER_PI));
+                let interior = radix * (x + c * x.powi(3));
+                let t = interior.tanh();
+                let derivata_interior = radix * (unus + T::ex_f64(3.0) * c * x * x);
+                dimidium * (unus + t) + dimidium * x * (unus - t * t) * derivata_interior
+            }
+            Activatio::Softplus => sigmoide(x),
+            Activatio::Identitas => unus,
+            Activatio::Softmax => unreachable!("Softmax per totum vectorem computatur."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    const X: [f64; 3] = [-2.0, 0.5, 3.0];
+
+    /// Probat valores et derivatas functionum per partes linearium.
+    /// Teste les valeurs et dérivées des fonctions linéaires par morceaux.
+    #[test]
+    fn probatio_per_partes_lineares() {
+        assert_eq!(Activatio::Relu.applica(&X), vec![0.0, 0.5, 3.0]);
+        assert_eq!(Activatio::ReluRimosa { alpha: 0.1 }.derivata(&X), vec![0.1, 1.0, 1.0]);
+        assert_eq!(Activatio::Identitas.derivata(&X), vec![1.0, 1.0, 1.0]);
+    }
+
+    /// Probat softmax probabilitates summae unitariae reddere.
+    /// Teste que softmax renvoie des probabilités de somme un.
+    #[test]
+    fn probatio_softmax() {
+        let probabilitates = Activatio::Softmax.applica(&X);
+        assert!((probabilitates.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    /// Probat derivatas cum differentiis finitis congruere.
+    /// Teste que les dérivées concordent avec les différences finies.
+    #[test]
+    fn probatio_derivatae_numericae() {
+        let h = 1e-6;
+        for activatio in [Activatio::Tanh, Activatio::Elu { alpha: 1.0 }, Activatio::Gelu, Activatio::Softplus] {
+            let derivata = activatio.derivata(&X);
+            for (i, &v) in X.iter().enumerate() {
+                let numerica = (activatio.applica(&[v + h])[0] - activatio.applica(&[v - h])[0]) / (2.0 * h);
+                assert!((derivata[i] - numerica).abs() < 1e-6, "{:?} in {}", activatio, v);
+            }
+        }
+    }
+}
@@ -1,11 +1,23 @@
 use clap::{Parser, Subcommand};
-use mininervus::{magister::MagisterNervorum, neuronatus::Neuronatus};
+use mininervus::{activatio::Activatio, exempla, initialisatio::Initialisatio, magister::MagisterNervorum, neuronatus::Neuronatus, normalizatio::Normalizatio, validatio::Validatio};
 use minitensor::Tensor1D;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::path::Path;
+use std::process;
 
 #[derive(Parser)]
 #[command(name = "mininervus")]
 #[command(about = "Magister artificialium neuronorum", long_about = None)]
 struct Cli {
+    /// Archivum spatii laboris, ubi retia inter mandata servantur
+    #[arg(short, long, global = true, default_value = "mininervus.json")]
+    workspace: String,
+
+    /// Semen generatoris fortuiti: idem semen eadem pondera initialia reddit
+    #[arg(long, global = true)]
+    semen: Option<u64>,
+
     #[command(subcommand)]
     command: Commande,
 }
@@ -18,12 +30,19 @@ enum Commande {
         nom: String,
         #[arg(short, long)]
         input: usize,
-        #[arg(short, long)]
-        hidden: usize,
+        /// Magnitudines stratorum occultorum, e.g. `-H 8,4`
+        #[arg(short = 'H', long, value_delimiter = ',', required = true)]
+        hidden: Vec<usize>,
         #[arg(short, long)]
         output: usize,
         #[arg(short, long, default_value_t = 0.3)]
         rate: f64,
+        /// Normalizatio stratorum occultorum: `batch` vel `strati`
+        #[arg(long)]
+        normalizatio: Option<String>,
+        /// Initialisatio ponderum: `xavier` (praefinita), `he`, `lecun`, `orthogonalis` vel `uniformis`; biases nulli
+        #[arg(long)]
+        initialisatio: Option<String>,
     },
 
     /// Praedictio
@@ -49,17 +68,122 @@ enum Commande {
         #[arg(short, long)]
         ex: String,
     },
+
+    /// Disce rete ex archivo exemplorum (CSV vel JSON-lines)
+    Disce {
+        #[arg(short, long)]
+        nom: String,
+        #[arg(short, long)]
+        ex: String,
+        #[arg(short = 'E', long, default_value_t = 1000)]
+        epochs: usize,
+        /// Exempla per renovationem (magnitudo minibatch)
+        #[arg(short, long)]
+        batch: Option<usize>,
+        /// Tonde omne elementum gradientis in [−v, v]
+        #[arg(long)]
+        tonsura_valoris: Option<f64>,
+        /// Tonde normam L2 globalem gradientis ad summum n
+        #[arg(long)]
+        tonsura_normae: Option<f64>,
+        /// Poena L1 in ponderibus (λ₁)
+        #[arg(long)]
+        l1: Option<f64>,
+        /// Poena L2 in ponderibus (λ₂)
+        #[arg(long)]
+        l2: Option<f64>,
+        /// Probabilitas omissionis (dropout) in stratis occultis
+        #[arg(long)]
+        omissio: Option<f64>,
+        /// Fractio exemplorum fortuito electa validationi seposita (cum --semen reproducibilis), e.g. 0.2
+        #[arg(long, conflicts_with = "exempla_validationis")]
+        validatio: Option<f64>,
+        /// Archivum exemplorum validationis (CSV vel JSON-lines)
+        #[arg(long)]
+        exempla_validationis: Option<String>,
+        /// Siste post n epochas sine progressu validationis
+        #[arg(long)]
+        patientia: Option<usize>,
+        #[arg(short, long)]
+        silentium: bool,
+    },
+
+    /// Enumera retia in spatio laboris
+    Nomina,
+}
+
+/// Onera spatium laboris, vel novum crea si archivum nondum exstat.
+/// Charge l'espace de travail, ou en crée un nouveau si le fichier n'existe pas encore.
+fn onera_spatium(via: &str) -> MagisterNervorum {
+    if !Path::new(via).exists() {
+        return MagisterNervorum::novus();
+    }
+
+    match MagisterNervorum::restitue_ex(via) {
+        Ok(magister) => magister,
+        Err(e) => {
+            eprintln!("Error in restitutione spatii '{}': {}", via, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Salva spatium laboris post mutationem.
+/// Sauvegarde l'espace de travail après une modification.
+fn salva_spatium(magister: &MagisterNervorum, via: &str) {
+    if let Err(e) = magister.salva_in(via) {
+        eprintln!("Error in salvatione spatii '{}': {}", via, e);
+        process::exit(1);
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
 
-    let mut magister = MagisterNervorum::novus();
+    let mut magister = onera_spatium(&cli.workspace);
 
     match cli.command {
-        Commande::Crea { nom, input, hidden, output, rate } => {
-            let rete = Neuronatus::novus(input, hidden, output, rate);
+        Commande::Crea { nom, input, hidden, output, rate, normalizatio, initialisatio } => {
+            let mut magnitudines = vec![input];
+            magnitudines.extend(hidden);
+            magnitudines.push(output);
+
+            let pesi = match initialisatio.as_deref() {
+                None => Initialisatio::pro_activatione(Activatio::Sigmoides),
+                Some("xavier") => Initialisatio::XavierUniformis,
+                Some("he") => Initialisatio::HeUniformis,
+                Some("lecun") => Initialisatio::LeCunUniformis,
+                Some("orthogonalis") => Initialisatio::orthogonalis(),
+                Some("uniformis") => Initialisatio::Uniformis,
+                Some(alia) => {
+                    eprintln!("Initialisatio '{}' ignota (xavier, he, lecun, orthogonalis vel uniformis).", alia);
+                    process::exit(1);
+                }
+            };
+            let mut rete = match cli.semen {
+                Some(semen) => {
+                    let mut aleator = ChaCha8Rng::seed_from_u64(semen);
+                    let mut rete = Neuronatus::ex_magnitudinibus_initialisatum_cum(
+                        &magnitudines, rate, pesi, Initialisatio::Nullus, &mut aleator,
+                    );
+                    rete.pone_semen(semen);
+                    rete
+                }
+                None => Neuronatus::ex_magnitudinibus_initialisatum_cum(
+                    &magnitudines, rate, pesi, Initialisatio::Nullus, &mut rand::rng(),
+                ),
+            };
+            match normalizatio.as_deref() {
+                None => {}
+                Some("batch") => rete.pone_normalizationem(Normalizatio::batch),
+                Some("strati") => rete.pone_normalizationem(Normalizatio::strati),
+                Some(alia) => {
+                    eprintln!("Normalizatio '{}' ignota (batch vel strati).", alia);
+                    process::exit(1);
+                }
+            }
             magister.adde(&nom, rete);
+            salva_spatium(&magister, &cli.workspace);
             println!("Rete '{}' creatum est.", nom);
         }
 
@@ -87,11 +211,157 @@ fn main() {
             match Neuronatus::restitue_ex(&ex) {
                 Ok(rete) => {
                     magister.adde(&nom, rete);
+                    salva_spatium(&magister, &cli.workspace);
                     println!("Rete '{}' oneratim est ex '{}'", nom, ex);
                 }
                 Err(e) => eprintln!("Error in restitutione: {}", e),
             }
         }
+
+        Commande::Disce { nom, ex, epochs, batch, tonsura_valoris, tonsura_normae, l1, l2, omissio, validatio, exempla_validationis, patientia, silentium } => {
+            let Some(rete) = magister.retia.get_mut(&nom) else {
+                eprintln!("Rete '{}' non est inventum.", nom);
+                process::exit(1);
+            };
+
+            let (intratae, exspectatae) = match exempla::lege(&ex, rete.input()) {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("Error in lectione exemplorum: {}", e);
+                    process::exit(1);
+                }
+            };
+
+            if intratae.latitudo != rete.input() || exspectatae.latitudo != rete.output() {
+                eprintln!(
+                    "Exempla ({} → {}) cum rete '{}' ({} → {}) non congruunt.",
+                    intratae.latitudo, exspectatae.latitudo, nom, rete.input(), rete.output()
+                );
+                process::exit(1);
+            }
+
+            if let Some(batch) = batch {
+                rete.magnitudo_batch = batch;
+            }
+            if rete.normalizatio_per_batch() && rete.magnitudo_batch < 2 {
+                eprintln!("Normalizatio per batch magnitudinem batch saltem 2 requirit (--batch).");
+                process::exit(1);
+            }
+            if [tonsura_valoris, tonsura_normae].into_iter().flatten().any(|limes| !(limes > 0.0 && limes.is_finite())) {
+                eprintln!("Limes tonsurae positivus esse debet.");
+                process::exit(1);
+            }
+            if tonsura_valoris.is_some() {
+                rete.tonsura.valor_maximus = tonsura_valoris;
+            }
+            if tonsura_normae.is_some() {
+                rete.tonsura.norma_maxima = tonsura_normae;
+            }
+            if [l1, l2].into_iter().flatten().any(|lambda| !(lambda >= 0.0 && lambda.is_finite())) {
+                eprintln!("Coefficiens regularizationis finitus et non negativus esse debet.");
+                process::exit(1);
+            }
+            if let Some(l1) = l1 {
+                rete.regularizatio.l1 = l1;
+            }
+            if let Some(l2) = l2 {
+                rete.regularizatio.l2 = l2;
+            }
+            if let Some(omissio) = omissio {
+                if !(0.0..1.0).contains(&omissio) {
+                    eprintln!("Probabilitas omissionis in [0, 1) esse debet.");
+                    process::exit(1);
+                }
+                rete.pone_omissionem(omissio);
+            }
+            if let Some(semen) = cli.semen {
+                rete.pone_semen(semen);
+            }
+
+            if patientia.is_some() && validatio.is_none() && exempla_validationis.is_none() {
+                eprintln!("Optio --patientia validationem requirit (--validatio vel --exempla-validationis).");
+                process::exit(1);
+            }
+            if patientia.is_some() {
+                rete.cessatio.patientia = patientia;
+            }
+
+            let validationis = match exempla_validationis.map(|via| exempla::lege(&via, rete.input())) {
+                Some(Ok((intratae_v, exspectatae_v))) => {
+                    if intratae_v.latitudo != rete.input() || exspectatae_v.latitudo != rete.output() {
+                        eprintln!("Exempla validationis cum rete '{}' non congruunt.", nom);
+                        process::exit(1);
+                    }
+                    Some((intratae_v, exspectatae_v))
+                }
+                Some(Err(e)) => {
+                    eprintln!("Error in lectione exemplorum validationis: {}", e);
+                    process::exit(1);
+                }
+                None => None,
+            };
+            if let Some(fractio) = validatio {
+                let seposita = ((intratae.profunditas as f64 * fractio).round() as usize).max(1);
+                if !(fractio > 0.0 && fractio < 1.0) || seposita >= intratae.profunditas {
+                    eprintln!("Fractio validationis in (0, 1) esse debet et exempla disciplinae relinquere.");
+                    process::exit(1);
+                }
+            }
+
+            let peractae = match (&validationis, validatio) {
+                (Some((intratae_v, exspectatae_v)), _) => {
+                    let validatio = Validatio::Exempla { intratae: intratae_v, exspectatae: exspectatae_v };
+                    rete.disciplina_validata(&intratae, &exspectatae, validatio, epochs, silentium).epochae.len()
+                }
+                (None, Some(fractio)) => {
+                    rete.disciplina_validata(&intratae, &exspectatae, Validatio::Fractio(fractio), epochs, silentium).epochae.len()
+                }
+                (None, None) => {
+                    rete.disciplina(&intratae, &exspectatae, epochs, silentium);
+                    epochs
+                }
+            };
+            salva_spatium(&magister, &cli.workspace);
+            println!("Rete '{}' per {} epochas eruditum est.", nom, peractae);
+        }
+
+        Commande::Nomina => {
+            let mut nomina = magister.nomina();
+            nomina.sort();
+            for nomen in nomina {
+                println!("{}", nomen);
+            }
+        }
     }
 }
 
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Probat spatium laboris post salvationem et onerationem eadem retia reddere.
+    /// Teste qu’un espace de travail sauvegardé puis rechargé rend les mêmes réseaux.
+    #[test]
+    fn probatio_spatium_laboris() {
+        let via = std::env::temp_dir().join("probatio_spatium_laboris.json");
+        let via = via.to_str().unwrap();
+        let _ = std::fs::remove_file(via);
+
+        // Archivum absens: spatium vacuum.
+        // Fichier absent : espace vide.
+        assert!(onera_spatium(via).nomina().is_empty());
+
+        let mut magister = MagisterNervorum::novus();
+        magister.adde("xor", Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.3, 7));
+        salva_spatium(&magister, via);
+
+        let restitutus = onera_spatium(via);
+        assert_eq!(restitutus.nomina(), vec!["xor".to_string()]);
+        let intrata = Tensor1D::ex_vec(vec![1.0, 0.0]);
+        let (a, b) = (restitutus.praedictio("xor", &intrata).unwrap(), magister.praedictio("xor", &intrata).unwrap());
+        assert!((a.materia[0] - b.materia[0]).abs() < 1e-12);
+
+        std::fs::remove_file(via).unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use minitensor::Tensor3D;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// Exemplum unum in archivo JSON-lines.
+/// Un exemple dans un fichier JSON-lines.
+#[derive(Deserialize)]
+struct ExemplumJson {
+    inputum: Vec<f64>,
+    exspectatum: Vec<f64>,
+}
+
+/// Lege exempla ex archivo, formam per extensionem eligens (`.csv` vel `.jsonl`/`.json`).
+/// Lit des exemples depuis un fichier, le format étant choisi selon l’extension.
+pub fn lege(via: &str, magnitudo_inputi: usize) -> io::Result<(Tensor3D, Tensor3D)> {
+    let extensio = Path::new(via)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+
+    match extensio.as_deref() {
+        Some("csv") => lege_csv(via, magnitudo_inputi),
+        Some("jsonl") | Some("json") => lege_jsonl(via),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Forma archivi '{}' ignota est (csv vel jsonl expectatur).", via),
+        )),
+    }
+}
+
+/// Lege exempla ex archivo CSV: primae `magnitudo_inputi` columnae sunt inputum, ceterae exspectatum.
+/// Lit un CSV : les premières colonnes sont l’entrée, les suivantes la cible.
+pub fn lege_csv(via: &str, magnitudo_inputi: usize) -> io::Result<(Tensor3D, Tensor3D)> {
+    let reader = BufReader::new(File::open(via)?);
+    let mut inputa = Vec::new();
+    let mut exspectata = Vec::new();
+    let mut caput_omissum = false;
+
+    for (numerus, linea) in reader.lines().enumerate() {
+        let linea = linea?;
+        let linea = linea.trim();
+        if linea.is_empty() || linea.starts_with('#') {
+            continue;
+        }
+
+        let valores: Result<Vec<f64>, _> = linea.split(',').map(|v| v.trim().parse::<f64>()).collect();
+        let valores = match valores {
+            Ok(valores) => valores,
+            // Prima linea non numerica est caput (header) et omittitur.
+            // Une première ligne non numérique est un en-tête et est ignorée.
+            Err(_) if inputa.is_empty() && !caput_omissum => {
+                caput_omissum = true;
+                continue;
+            }
+            Err(e) => return Err(error_datorum(via, numerus, &e.to_string())),
+        };
+
+        if valores.len() <= magnitudo_inputi {
+            return Err(error_datorum(
+                via,
+                numerus,
+                &format!("{} columnae inventae, plus quam {} expectantur", valores.len(), magnitudo_inputi),
+            ));
+        }
+
+        let (inputum, exspectatum) = valores.split_at(magnitudo_inputi);
+        inputa.push(vec![inputum.to_vec()]);
+        exspectata.push(vec![exspectatum.to_vec()]);
+    }
+
+    collige(via, inputa, exspectata)
+}
+
+/// Lege exempla ex archivo JSON-lines: `{"inputum": [..], "exspectatum": [..]}` per lineam.
+/// Lit un fichier JSON-lines : un objet `{"inputum", "exspectatum"}` par ligne.
+pub fn lege_jsonl(via: &str) -> io::Result<(Tensor3D, Tensor3D)> {
+    let reader = BufReader::new(File::open(via)?);
+    let mut inputa = Vec::new();
+    let mut exspectata = Vec::new();
+
+    for (numerus, linea) in reader.lines().enumerate() {
+        let linea = linea?;
+        if linea.trim().is_empty() {
+            continue;
+        }
+
+        let exemplum: ExemplumJson = serde_json::from_str(&linea)
+            .map_err(|e| error_datorum(via, numerus, &e.to_string()))?;
+        inputa.push(vec![exemplum.inputum]);
+        exspectata.push(vec![exemplum.exspectatum]);
+    }
+
+    collige(via, inputa, exspectata)
+}
+
+/// Verifica formas et converte in Tensor3D [exempla][1][magnitudo].
+/// Vérifie les formes et convertit en Tensor3D [exemples][1][taille].
+fn collige(
+    via: &str,
+    inputa: Vec<Vec<Vec<f64>>>,
+    exspectata: Vec<Vec<Vec<f64>>>,
+) -> io::Result<(Tensor3D, Tensor3D)> {
+    if inputa.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Archivum '{}' nulla exempla continet.", via),
+        ));
+    }
+
+    for (index, (inputum, exspectatum)) in inputa.iter().zip(&exspectata).enumerate() {
+        if inputum[0].len() != inputa[0][0].len() || exspectatum[0].len() != exspectata[0][0].len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: exemplum {} magnitudine a primo differt.", via, index + 1),
+            ));
+        }
+    }
+
+    Ok((Tensor3D::ex_vec(inputa), Tensor3D::ex_vec(exspectata)))
+}
+
+/// Error datorum cum via et numero lineae.
+/// Erreur de données avec chemin et numéro de ligne.
+fn error_datorum(via: &str, numerus: usize, nuntius: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}:{}: {}", via, numerus + 1, nuntius),
+    )
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Probat lectionem exemplorum ex archivo CSV cum capite.
+    /// Teste la lecture d’exemples depuis un CSV avec en-tête.
+    #[test]
+    fn probatio_exempla_csv() {
+        let via = std::env::temp_dir().join("probatio_exempla_csv.csv");
+        std::fs::write(&via, "a,b,y\n0,1,1\n1,1,0\n").unwrap();
+
+        let (intratae, exspectatae) = lege(via.to_str().unwrap(), 2).unwrap();
+        assert_eq!(intratae.profunditas, 2);
+        assert_eq!(intratae.ordo(1, 0), &[1.0, 1.0]);
+        assert_eq!(exspectatae.ordo(0, 0), &[1.0]);
+
+        std::fs::remove_file(via).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+//
+// GRADIENTES
+// ----------
+// Gradients de la perte, structurés comme les paramètres du réseau (une entrée par couche).
+//
+
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use crate::neuronatus::NeuronatusT;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error as _;
+
+/// Gradientes unius strati, eiusdem formae ac pondera et bias eius.
+/// Gradients d’une couche, de même forme que ses poids et son biais.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientesStrati<T = f64> {
+    pub pesi: TensorT2D<T>, // ∂L/∂W [sortie x entrée]
+    pub bias: TensorT1D<T>, // ∂L/∂b [sortie]
+    pub normalizatio: Option<GradientesNormalizationis<T>>,  // ∂L/∂γ, ∂L/∂β si la couche est normalisée
+}
+
+/// Gradientes scalae et translationis normalizationis unius strati.
+/// Gradients de l’échelle et du décalage de la normalisation d’une couche.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientesNormalizationis<T = f64> {
+    pub scala: TensorT1D<T>,      // ∂L/∂γ [sortie]
+    pub translatio: TensorT1D<T>, // ∂L/∂β [sortie]
+}
+
+/// Gradientes totius retis, ab strato primo ad ultimum.
+/// Gradients de tout le réseau, de la première couche à la dernière.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradientes<T = f64> {
+    pub strata: Vec<GradientesStrati<T>>,
+}
+
+/// Tonsura gradientum inter computationem et renovationem ponderum; `None` = inactiva.
+/// Écrêtage des gradients entre leur calcul et la mise à jour des poids ; `None` = inactif.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tonsura {
+    #[serde(default, deserialize_with = "lege_limitem")]
+    pub valor_maximus: Option<f64>,  // Chaque élément est borné à [−v, v]
+
+    #[serde(default, deserialize_with = "lege_limitem")]
+    pub norma_maxima: Option<f64>,   // La norme L2 globale est ramenée à au plus n
+}
+
+impl Tonsura {
+    /// Tonsura per valorem: omne elementum in [−limes, limes] coercetur; limes positivus esse debet.
+/// Écrêtage par valeur : chaque élément est borné à [−limes, limes] ; la limite doit être positive.
+    pub fn per_valorem(limes: f64) -> Self {
+        verifica_limitem(limes);
+        Tonsura { valor_maximus: Some(limes), norma_maxima: None }
+    }
+
+    /// Tonsura per normam globalem: si ‖g‖₂ > limes, omnes gradientes per limes / ‖g‖₂ multiplicantur;
+    /// limes positivus esse debet.
+/// Écrêtage par norme globale : si ‖g‖₂ > limes, tous les gradients sont multipliés par limes / ‖g‖₂ ;
+/// la limite doit être positive.
+    pub fn per_normam(limes: f64) -> Self {
+        verifica_limitem(limes);
+        Tonsura { valor_maximus: None, norma_maxima: Some(limes) }
+    }
+
+    /// Verum si aliqua tonsura activa est.
+/// Vrai si un écrêtage est actif.
+    pub fn activa(&self) -> bool {
+        self.valor_maximus.is_some() || self.norma_maxima.is_some()
+    }
+
+    /// Verum si omnes limites positi finiti et positivi sunt (campi publici mutari possunt).
+/// Vrai si toutes les limites fixées sont finies et positives (les champs publics peuvent être modifiés).
+    pub fn valida(&self) -> bool {
+        self.valor_maximus.into_iter().chain(self.norma_maxima).all(limes_validus)
+    }
+}
+
+impl<T: Numerus> Gradientes<T> {
+    /// Gradientes nulli eiusdem formae ac parametra retis, e.g. ad accumulationem.
+/// Gradients nuls de même forme que les paramètres du réseau, p. ex. pour l’accumulation.
+    pub fn nullus(rete: &NeuronatusT<T>) -> Self {
+        let strata = rete
+            .strata
+            .iter()
+            .map(|stratum| GradientesStrati {
+                pesi: TensorT2D::nullus(stratum.pesi.ordines, stratum.pesi.columnae),
+                bias: TensorT1D::nullus(stratum.bias.magnitudo),
+                normalizatio: stratum.normalizatio.as_ref().map(|normalizatio| GradientesNormalizationis {
+                    scala: TensorT1D::nullus(normalizatio.magnitudo()),
+                    translatio: TensorT1D::nullus(normalizatio.magnitudo()),
+                }),
+            })
+            .collect();
+        Self { strata }
+    }
+
+    /// Adde alios gradientes (eiusdem formae) his.
+/// Ajoute d’autres gradients (de même forme) à ceux-ci.
+    pub fn adde(&mut self, alius: &Gradientes<T>) {
+        assert_eq!(self.strata.len(), alius.strata.len(), "Numerus stratorum non congruit.");
+        for (hic, ille) in self.strata.iter_mut().zip(&alius.strata) {
+            hic.pesi += &ille.pesi;
+            hic.bias += &ille.bias;
+            if let (Some(hic), Some(ille)) = (&mut hic.normalizatio, &ille.normalizatio) {
+                hic.scala += &ille.scala;
+                hic.translatio += &ille.translatio;
+            }
+        }
+    }
+
+    /// Multiplica omnes gradientes per factorem, e.g. 1/n post accumulationem n exemplorum.
+/// Multiplie tous les gradients par un facteur, p. ex. 1/n après avoir accumulé n exemples.
+    pub fn multiplica(&mut self, factor: T) {
+        for stratum in &mut self.strata {
+            stratum.pesi *= factor;
+            stratum.bias *= factor;
+            if let Some(normalizatio) = &mut stratum.normalizatio {
+                normalizatio.scala *= factor;
+                normalizatio.translatio *= factor;
+            }
+        }
+    }
+
+    /// Norma L2 globalis per omnia parametra omnium stratorum.
+/// Norme L2 globale sur tous les paramètres de toutes les couches.
+    pub fn norma(&self) -> T {
+        self.strata
+            .iter()
+            .map(|stratum| stratum.elementa().map(|&g| g * g).sum::<T>())
+            .sum::<T>()
+            .sqrt()
+    }
+
+    /// Tonde gradientes secundum tonsuram (prius per valorem, deinde per normam);
+    /// reddit normam L2 globalem ante tonsuram, ad monitionem.
+/// Écrête les gradients selon la configuration (d’abord par valeur, puis par norme) ;
+/// renvoie la norme L2 globale avant écrêtage, pour le suivi.
+    /// Limites invalidi (vide `Tonsura::valida`) neglecti sunt; disciplina eos ante initium reicit.
+/// Les limites invalides (voir `Tonsura::valida`) sont ignorées ; disciplina les rejette avant de commencer.
+    pub fn tonde(&mut self, tonsura: &Tonsura) -> T {
+        let norma = self.norma();
+
+        if let Some(limes) = tonsura.valor_maximus.filter(|&limes| limes_validus(limes)) {
+            let limes = T::ex_f64(limes);
+            for stratum in &mut self.strata {
+                stratum.elementa_mut().for_each(|g| *g = g.max(-limes).min(limes));
+            }
+        }
+
+        if let Some(limes) = tonsura.norma_maxima.filter(|&limes| limes_validus(limes)) {
+            let limes = T::ex_f64(limes);
+            let norma = self.norma();
+            if norma > limes {
+                self.multiplica(limes / norma);
+            }
+        }
+
+        norma
+    }
+}
+
+impl<T: Numerus> GradientesStrati<T> {
+    /// Omnia elementa gradientis strati (pesi, bias, deinde γ et β si adsunt).
+/// Tous les éléments du gradient de la couche (poids, biais, puis γ et β s’il y en a).
+    fn elementa(&self) -> impl Iterator<Item = &T> {
+        let normalizatio = self.normalizatio.iter().flat_map(|n| n.scala.materia.iter().chain(&n.translatio.materia));
+        self.pesi.elementa.iter().chain(&self.bias.materia).chain(normalizatio)
+    }
+
+    /// Omnia elementa gradientis strati, mutabilia.
+/// Tous les éléments du gradient de la couche, modifiables.
+    fn elementa_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let normalizatio = self
+            .normalizatio
+            .iter_mut()
+            .flat_map(|n| n.scala.materia.iter_mut().chain(n.translatio.materia.iter_mut()));
+        self.pesi.elementa.iter_mut().chain(self.bias.materia.iter_mut()).chain(normalizatio)
+    }
+}
+
+/// Limes tonsurae finitus et positivus esse debet (limes ≤ 0 omnes gradientes deleret).
+/// La limite d’écrêtage doit être finie et positive (une limite ≤ 0 annulerait tous les gradients).
+fn limes_validus(limes: f64) -> bool {
+    limes > 0.0 && limes.is_finite()
+}
+
+/// Panicum facit si limes tonsurae invalidus est.
+/// Panique si la limite d’écrêtage est invalide.
+fn verifica_limitem(limes: f64) {
+    assert!(limes_validus(limes), "Limes tonsurae positivus esse debet, {} datus.", limes);
+}
+
+/// Lege limitem tonsurae (si adest) et verifica eum finitum et positivum esse.
+/// Lit la limite d’écrêtage (si présente) et vérifie qu’elle est finie et positive.
+fn lege_limitem<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let limes = Option::<f64>::deserialize(deserializer)?;
+    match limes {
+        Some(limes) if !limes_validus(limes) => {
+            Err(D::Error::custom(format!("Limes tonsurae positivus esse debet, {} datus.", limes)))
+        }
+        _ => Ok(limes),
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Probat constructores limites non positivos reicere.
+    /// Teste que les constructeurs rejettent les limites non positives.
+    #[test]
+    fn probatio_tonsura_constructores() {
+        assert!(std::panic::catch_unwind(|| Tonsura::per_valorem(0.0)).is_err());
+        assert!(std::panic::catch_unwind(|| Tonsura::per_normam(-1.0)).is_err());
+    }
+
+    /// Probat limites in archivo verificari.
+    /// Teste que les limites sont vérifiées à la lecture d’un fichier.
+    #[test]
+    fn probatio_tonsura_archivum() {
+        assert!(serde_json::from_str::<Tonsura>(r#"{"valor_maximus":null,"norma_maxima":-2.0}"#).is_err());
+        assert_eq!(serde_json::from_str::<Tonsura>(r#"{"norma_maxima":2.0}"#).unwrap(), Tonsura::per_normam(2.0));
+    }
+
+    /// Probat `valida` limites in campis publicis positos iudicare.
+    /// Teste que `valida` juge les limites placées dans les champs publics.
+    #[test]
+    fn probatio_tonsura_valida() {
+        assert!(Tonsura::default().valida() && Tonsura::per_normam(1.0).valida());
+        assert!(!Tonsura { valor_maximus: Some(f64::NAN), norma_maxima: Some(0.0) }.valida());
+    }
+}
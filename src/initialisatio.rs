@@ -0,0 +1,178 @@
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use crate::activatio::Activatio;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+/// Ratio initialisationis ponderum vel biasis, per stratum eligibilis.
+/// Schéma d’initialisation des poids ou des biais, sélectionnable pour chaque couche.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Initialisatio {
+    /// U[0, 1): ratio antiqua, pro retibus profundis non apta.
+/// U[0, 1) : schéma historique, inadapté aux réseaux profonds.
+    #[default]
+    Uniformis,
+
+    /// Omnia nulla (praesertim pro biasibus).
+/// Tout à zéro (surtout pour les biais).
+    Nullus,
+
+    /// Glorot: U[−a, a], a = √(6 / (fan_in + fan_out)).
+/// Glorot : U[−a, a], a = √(6 / (fan_in + fan_out)).
+    XavierUniformis,
+
+    /// Glorot: N(0, 2 / (fan_in + fan_out)).
+/// Glorot : N(0, 2 / (fan_in + fan_out)).
+    XavierNormalis,
+
+    /// Kaiming (pro ReLU): U[−a, a], a = √(6 / fan_in).
+/// Kaiming (pour ReLU) : U[−a, a], a = √(6 / fan_in).
+    HeUniformis,
+
+    /// Kaiming (pro ReLU): N(0, 2 / fan_in).
+/// Kaiming (pour ReLU) : N(0, 2 / fan_in).
+    HeNormalis,
+
+    /// LeCun (pro SELU): U[−a, a], a = √(3 / fan_in).
+/// LeCun (pour SELU) : U[−a, a], a = √(3 / fan_in).
+    LeCunUniformis,
+
+    /// LeCun (pro SELU): N(0, 1 / fan_in).
+/// LeCun (pour SELU) : N(0, 1 / fan_in).
+    LeCunNormalis,
+
+    /// Matrix orthogonalis (per QR matricis normalis), multiplicata per `lucrum`.
+/// Matrice orthogonale (via la QR d’une matrice normale), multipliée par `lucrum`.
+    Orthogonalis { lucrum: f64 },
+}
+
+impl Initialisatio {
+    /// Orthogonalis cum lucro unitario.
+/// Orthogonale de gain unité.
+    pub fn orthogonalis() -> Self {
+        Initialisatio::Orthogonalis { lucrum: 1.0 }
+    }
+
+    /// Ratio ponderum praefinita pro activatione: He pro familia ReLU, Xavier pro ceteris (sigmoide, tanh...).
+/// Schéma de poids par défaut selon l’activation : He pour la famille ReLU, Xavier pour les autres (sigmoïde, tanh…).
+    pub fn pro_activatione(activatio: Activatio) -> Self {
+        match activatio {
+            Activatio::Relu | Activatio::ReluRimosa { .. } | Activatio::Elu { .. } | Activatio::Gelu | Activatio::Softplus => {
+                Initialisatio::HeUniformis
+            }
+            _ => Initialisatio::XavierUniformis,
+        }
+    }
+
+    /// Matrix ponderum [exitus x introitus]; fan_in = introitus, fan_out = exitus.
+/// Matrice des poids [sortie x entrée] ; fan_in = entrée, fan_out = sortie.
+    pub fn pondera<T: Numerus, R: Rng + ?Sized>(&self, exitus: usize, introitus: usize, aleator: &mut R) -> TensorT2D<T> {
+        self.matrix(exitus, introitus, introitus, exitus, aleator)
+    }
+
+    /// Vector biasis strati [exitus], cum iisdem fan_in et fan_out ac pondera.
+/// Vecteur des biais de la couche [sortie], avec les mêmes fan_in et fan_out que les poids.
+    pub fn bias<T: Numerus, R: Rng + ?Sized>(&self, exitus: usize, introitus: usize, aleator: &mut R) -> TensorT1D<T> {
+        self.matrix(exitus, 1, introitus, exitus, aleator).columna(0)
+    }
+
+    fn matrix<T: Numerus, R: Rng + ?Sized>(
+        &self,
+        ordines: usize,
+        columnae: usize,
+        fan_in: usize,
+        fan_out: usize,
+        aleator: &mut R,
+    ) -> TensorT2D<T> {
+        let (fan_in, fan_out) = (fan_in.max(1) as f64, fan_out.max(1) as f64);
+        let uniformis = |a: f64, aleator: &mut R| TensorT2D::uniformis_cum(ordines, columnae, -a, a, aleator);
+
+        match *self {
+            Initialisatio::Uniformis => TensorT2D::fortuitus_cum(ordines, columnae, aleator),
+            Initialisatio::Nullus => TensorT2D::nullus(ordines, columnae),
+            Initialisatio::XavierUniformis => uniformis((6.0 / (fan_in + fan_out)).sqrt(), aleator),
+            Initialisatio::XavierNormalis => {
+                TensorT2D::normalis_cum(ordines, columnae, 0.0, (2.0 / (fan_in + fan_out)).sqrt(), aleator)
+            }
+            Initialisatio::HeUniformis => uniformis((6.0 / fan_in).sqrt(), aleator),
+            Initialisatio::HeNormalis => TensorT2D::normalis_cum(ordines, columnae, 0.0, (2.0 / fan_in).sqrt(), aleator),
+            Initialisatio::LeCunUniformis => uniformis((3.0 / fan_in).sqrt(), aleator),
+            Initialisatio::LeCunNormalis => TensorT2D::normalis_cum(ordines, columnae, 0.0, (1.0 / fan_in).sqrt(), aleator),
+            Initialisatio::Orthogonalis { lucrum } => orthogonalis(ordines, columnae, lucrum, aleator),
+        }
+    }
+}
+
+/// Matrix [ordines x columnae] cum ordinibus (vel columnis) orthonormalibus.
+/// Matrice [lignes x colonnes] aux lignes (ou colonnes) orthonormées.
+fn orthogonalis<T: Numerus, R: Rng + ?Sized>(ordines: usize, columnae: usize, lucrum: f64, aleator: &mut R) -> TensorT2D<T> {
+    // QR matricis altae [maior x minor]: primae columnae Q orthonormales sunt.
+    // QR d’une matrice haute [grand x petit] : les premières colonnes de Q sont orthonormées.
+    let (maior, minor) = (ordines.max(columnae), ordines.min(columnae));
+    let qr = TensorT2D::<f64>::normalis_cum(maior, minor, 0.0, 1.0, aleator).qr();
+
+    // Signa diagonalis R corriguntur, ut distributio uniformis (Haar) sit.
+    // Les signes de la diagonale de R sont corrigés pour une distribution uniforme (Haar).
+    let mut q = qr.q.seca_columnas(0..minor);
+    for j in 0..minor {
+        let signum = if qr.r[(j, j)] < 0.0 { -lucrum } else { lucrum };
+        for i in 0..maior {
+            q[(i, j)] *= signum;
+        }
+    }
+
+    let q = if ordines < columnae { q.transpone() } else { q };
+    q.converte()
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+    use minitensor::Tensor2D;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Probat Xavier uniformem intra limitem suum manere.
+    /// Teste que Xavier uniforme reste dans sa borne.
+    #[test]
+    fn probatio_xavier() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        let xavier: Tensor2D = Initialisatio::XavierUniformis.pondera(30, 70, &mut aleator);
+        assert!(xavier.norma_maxima() <= (6.0_f64 / 100.0).sqrt());
+    }
+
+    /// Probat He normalem variantiam 2 / fan_in habere.
+    /// Teste que He normale a une variance 2 / fan_in.
+    #[test]
+    fn probatio_he() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        let he: Tensor2D = Initialisatio::HeNormalis.pondera(400, 50, &mut aleator);
+        assert!((he.map(|x| x * x).media() - 2.0 / 50.0).abs() < 0.004);
+    }
+
+    /// Probat ordines (vel columnas) orthonormales esse, pro utraque forma.
+    /// Teste que les lignes (ou colonnes) sont orthonormées, pour les deux formes.
+    #[test]
+    fn probatio_orthogonalis() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        for (ordines, columnae) in [(3, 5), (5, 3), (4, 4)] {
+            let w: Tensor2D = Initialisatio::orthogonalis().pondera(ordines, columnae, &mut aleator);
+            let gram = if ordines <= columnae {
+                w.productum_matriciale(&w.transpone())
+            } else {
+                w.transpone().productum_matriciale(&w)
+            };
+            let differentia = &gram - &Tensor2D::identitas(ordines.min(columnae));
+            assert!(differentia.norma_maxima() < 1e-12);
+        }
+    }
+
+    /// Probat rationem praefinitam ex activatione pendere: Xavier pro sigmoide et tanh, He pro familia ReLU.
+    /// Teste que le schéma par défaut dépend de l’activation : Xavier pour sigmoïde et tanh, He pour la famille ReLU.
+    #[test]
+    fn probatio_pro_activatione() {
+        assert_eq!(Initialisatio::pro_activatione(Activatio::Sigmoides), Initialisatio::XavierUniformis);
+        assert_eq!(Initialisatio::pro_activatione(Activatio::Tanh), Initialisatio::XavierUniformis);
+        assert_eq!(Initialisatio::pro_activatione(Activatio::Relu), Initialisatio::HeUniformis);
+        assert_eq!(Initialisatio::pro_activatione(Activatio::ReluRimosa { alpha: 0.01 }), Initialisatio::HeUniformis);
+    }
+}
@@ -1,7 +1,18 @@
 pub mod tensor;
 pub mod neuronatus;
+pub mod stratum;
+pub mod gradientes;
+pub mod initialisatio;
 pub mod activatio;
+pub mod perditio;
+pub mod regularizatio;
+pub mod normalizatio;
+pub mod optimizator;
+pub mod schedula;
+pub mod validatio;
+pub mod verificatio;
 pub mod magister;
+pub mod exempla;
 
 #[cfg(test)]
 mod probationes {
@@ -18,7 +29,8 @@ mod probationes {
 
         assert_eq!(tensor.ordines, 2); // Expectatur duo ordines.
         assert_eq!(tensor.columnae, 2); // Expectatur duae columnae.
-        assert_eq!(tensor.materia, materia);
+        assert_eq!(tensor.materia(), materia);
+        assert_eq!(tensor.elementa, vec![1.0, 2.0, 3.0, 4.0]);
     }
 
     /// Probat creationem tensoris nulli.
@@ -32,8 +44,8 @@ mod probationes {
 
         // Omnis valor debet esse nullus.
         // Chaque valeur doit être égale à zéro.
-        for ordo in &tensor.materia {
-            assert_eq!(ordo, &vec![0.0, 0.0]);
+        for ordo in tensor.ordines_iter() {
+            assert_eq!(ordo, &[0.0, 0.0]);
         }
     }
 
@@ -45,7 +57,8 @@ mod probationes {
         assert_eq!(tensor.ordines, 3);
         assert_eq!(tensor.columnae, 2);
 
-        for ordo in &tensor.materia {
+        assert_eq!(tensor.elementa.len(), 6);
+        for ordo in tensor.ordines_iter() {
             assert_eq!(ordo.len(), 2);
         }
     }
@@ -83,6 +96,56 @@ mod probationes {
         assert_eq!(productum, expectatur);
     }
 
+    /// Probat productum per tegulas idem reddere ac productum simplex.
+    /// Teste que le produit par blocs égale le produit naïf.
+    #[test]
+    fn probatio_tensor2d_productum_tegulatum() {
+        // Formae non multiplices tegulae, ut margines quoque probentur.
+        // Formes non multiples de la taille de bloc, pour tester les bords.
+        let a = Tensor2D::fortuitus(70, 130);
+        let b = Tensor2D::fortuitus(130, 65);
+        let tegulatum = a.productum_matriciale(&b);
+        let simplex = a.productum_matriciale_simplex(&b);
+
+        assert_eq!(tegulatum.forma(), [70, 65]);
+        for (x, y) in tegulatum.elementa.iter().zip(&simplex.elementa) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+
+    /// Probat variantes `try_` errorem formae reddere pro panico.
+    /// Teste que les variantes `try_` renvoient une erreur de forme au lieu de paniquer.
+    #[test]
+    fn probatio_tensor_errores_formae() {
+        use super::tensor::{ErrorTensoris, Tensor3D};
+
+        let error = Tensor2D::try_ex_vec(vec![vec![1.0, 2.0], vec![3.0]]).unwrap_err();
+        assert_eq!(
+            error,
+            ErrorTensoris::OrdoInaequalis { operatio: "Tensor2D::ex_vec", index: vec![1], exspectata: 2, reperta: 1 }
+        );
+        assert!(Tensor2D::try_ex_vec(vec![]).is_err());
+
+        let a = Tensor2D::nullus(2, 3);
+        let b = Tensor2D::nullus(2, 3);
+        match a.try_productum_matriciale(&b) {
+            Err(ErrorTensoris::FormaeDiscrepantes { exspectata, reperta, .. }) => {
+                assert_eq!(exspectata, vec![3, 3]);
+                assert_eq!(reperta, vec![2, 3]);
+            }
+            alius => panic!("Error formae exspectatus, {:?} repertum.", alius),
+        }
+        assert!(a.try_adde(&Tensor2D::nullus(3, 2)).is_err());
+        assert!(a.try_adde(&b).is_ok());
+
+        let u = Tensor1D::ex_vec(vec![1.0, 2.0]);
+        assert!(u.try_productum_scalaris(&Tensor1D::nullus(3)).is_err());
+        assert!(u.try_adde(&Tensor1D::nullus(1)).is_err());
+
+        let error = Tensor3D::try_ex_vec(vec![vec![vec![1.0]], vec![vec![1.0, 2.0]]]).unwrap_err();
+        assert!(error.to_string().contains("[1, 0]"));
+    }
+
     /// Probat Tensor1D e vectore simplici.
     /// Teste la création d’un vecteur simple.
     #[test]
@@ -139,7 +202,7 @@ mod probationes {
         let columna = vector.transpone();
         assert_eq!(columna.ordines, 3);
         assert_eq!(columna.columnae, 1);
-        assert_eq!(columna.materia, vec![vec![1.0], vec![2.0], vec![3.0]]);
+        assert_eq!(columna.materia(), vec![vec![1.0], vec![2.0], vec![3.0]]);
     }
     
     use super::tensor::Tensor3D;
@@ -157,7 +220,9 @@ fn probatio_tensor3d_ex_vec() {
     assert_eq!(tensor.profunditas, 2);
     assert_eq!(tensor.altitudo, 2);
     assert_eq!(tensor.latitudo, 2);
-    assert_eq!(tensor.materia, materia);
+    assert_eq!(tensor.materia(), materia);
+    assert_eq!(tensor[(1, 0, 1)], 6.0);
+    assert_eq!(tensor.ordo(1, 1), &[7.0, 8.0]);
 }
 
 /// Probat creationem tensoris nulli 3D.
@@ -169,12 +234,9 @@ fn probatio_tensor3d_nullus() {
     assert_eq!(tensor.altitudo, 2);
     assert_eq!(tensor.latitudo, 4);
 
-    for planum in &tensor.materia {
-        for ordo in planum {
-            for &val in ordo {
-                assert_eq!(val, 0.0);
-            }
-        }
+    assert_eq!(tensor.elementa.len(), 24);
+    for &val in &tensor.elementa {
+        assert_eq!(val, 0.0);
     }
 }
 
@@ -188,5 +250,1210 @@ fn probatio_tensor3d_fortuitus() {
     assert_eq!(tensor.latitudo, 2);
 }
 
+/// Probat rete multorum stratorum occultorum.
+/// Teste un réseau à plusieurs couches cachées.
+#[test]
+fn probatio_neuronatus_multa_strata() {
+    use super::neuronatus::Neuronatus;
+
+    let rete = Neuronatus::ex_magnitudinibus(&[3, 5, 4, 2], 0.1);
+    assert_eq!(rete.strata.len(), 3);
+    assert_eq!(rete.input(), 3);
+    assert_eq!(rete.output(), 2);
+
+    let exitus = rete.praedictio(&Tensor1D::ex_vec(vec![0.1, 0.2, 0.3]));
+    assert_eq!(exitus.magnitudo, 2);
+}
+
+/// Probat constructorem novus idem esse ac rete unius strati occulti.
+/// Teste que le constructeur novus équivaut à un réseau à une couche cachée.
+#[test]
+fn probatio_neuronatus_novus() {
+    use super::neuronatus::Neuronatus;
+
+    let simplex = Neuronatus::novus(2, 3, 1, 0.1);
+    assert_eq!(simplex.strata.len(), 2);
+    assert_eq!(simplex.strata[0].exitus(), 3);
+}
+
+/// Probat statum optimizatoris cum rete servari, ut disciplina exacte resumatur.
+/// Teste que l’état de l’optimiseur est sauvegardé avec le réseau.
+#[test]
+fn probatio_optimizator_resumptio() {
+    use super::neuronatus::Neuronatus;
+    use super::optimizator::Optimizator;
+
+    let x = Tensor1D::ex_vec(vec![1.0, 0.0]);
+    let y = Tensor1D::ex_vec(vec![1.0]);
+
+    let mut rete = Neuronatus::novus(2, 3, 1, 0.05);
+    rete.pone_optimizatorem(Optimizator::adam());
+    rete.instruere(&x, &y);
+    rete.instruere(&x, &y);
+
+    let json = serde_json::to_string(&rete).unwrap();
+    let mut restitutum: Neuronatus = serde_json::from_str(&json).unwrap();
+    assert_eq!(restitutum.status_optimizatoris.passus, 2);
+
+    rete.instruere(&x, &y);
+    restitutum.instruere(&x, &y);
+    for (a, b) in rete.strata.iter().zip(&restitutum.strata) {
+        for (p, q) in a.pesi.elementa.iter().zip(&b.pesi.elementa) {
+            assert!((p - q).abs() < 1e-12);
+        }
+    }
+}
+
+/// Duo exempla unius ordinis pro probationibus minibatch.
+/// Deux exemples d’une ligne pour les tests de mini-lot.
+fn exempla_minibatch() -> (Tensor3D, Tensor3D) {
+    let intratae = Tensor3D::ex_vec(vec![vec![vec![0.0, 1.0]], vec![vec![1.0, 0.5]]]);
+    let exspectatae = Tensor3D::ex_vec(vec![vec![vec![1.0]], vec![vec![0.0]]]);
+    (intratae, exspectatae)
+}
+
+/// Probat renovationem minibatch mediam esse renovationum singulorum exemplorum.
+/// Teste qu’une mise à jour par mini-lot est la moyenne des mises à jour par exemple.
+#[test]
+fn probatio_minibatch_media() {
+    use super::neuronatus::Neuronatus;
+
+    let (intratae, exspectatae) = exempla_minibatch();
+    let initium = Neuronatus::ex_magnitudinibus(&[2, 1], 0.5);
+    let mut batch = initium.clone();
+    batch.magnitudo_batch = 2;
+    batch.instruere_batch(&intratae, &exspectatae);
+
+    let mut media = [0.0; 2];
+    for i in 0..2 {
+        let mut singulum = initium.clone();
+        singulum.instruere(
+            &Tensor1D::ex_vec(intratae.ordo(i, 0).to_vec()),
+            &Tensor1D::ex_vec(exspectatae.ordo(i, 0).to_vec()),
+        );
+        for (j, m) in media.iter_mut().enumerate() {
+            *m += (singulum.strata[0].pesi[(0, j)] - initium.strata[0].pesi[(0, j)]) / 2.0;
+        }
+    }
+
+    for (j, m) in media.iter().enumerate() {
+        let delta = batch.strata[0].pesi[(0, j)] - initium.strata[0].pesi[(0, j)];
+        assert!((delta - m).abs() < 1e-12);
+    }
+}
+
+/// Probat magnitudinem praefinitam (etiam in archivis veteribus) unum exemplum esse: instruere_batch
+/// idem facit ac instruere pro quoque exemplo.
+/// Teste que la taille par défaut (y compris dans les anciens fichiers) est d’un exemple : instruere_batch
+/// équivaut à instruere sur chaque exemple.
+#[test]
+fn probatio_minibatch_praefinitum() {
+    use super::neuronatus::Neuronatus;
+
+    let (intratae, exspectatae) = exempla_minibatch();
+    let initium = Neuronatus::ex_magnitudinibus(&[2, 1], 0.5);
+    let mut praefinitum = initium.clone();
+    assert_eq!(praefinitum.magnitudo_batch, 1);
+    praefinitum.instruere_batch(&intratae, &exspectatae);
+    let mut singula = initium.clone();
+    for i in 0..2 {
+        singula.instruere(
+            &Tensor1D::ex_vec(intratae.ordo(i, 0).to_vec()),
+            &Tensor1D::ex_vec(exspectatae.ordo(i, 0).to_vec()),
+        );
+    }
+    assert_eq!(praefinitum.strata[0].pesi, singula.strata[0].pesi);
+}
+
+/// Probat rete f32 discere et praedicere sicut rete f64.
+/// Teste qu’un réseau f32 apprend et prédit comme un réseau f64.
+#[test]
+fn probatio_praecisio_f32() {
+    use super::neuronatus::NeuronatusT;
+    use super::tensor::TensorT1D;
+
+    let mut rete = NeuronatusT::<f32>::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 11);
+    let intrata = TensorT1D::ex_vec(vec![0.5_f32, -0.5]);
+    let exspectata = TensorT1D::ex_vec(vec![1.0_f32]);
+    let ante = rete.perditio_exempli(&intrata, &exspectata);
+    for _ in 0..50 {
+        rete.instruere(&intrata, &exspectata);
+    }
+    assert!(rete.perditio_exempli(&intrata, &exspectata) < ante);
+}
+
+/// Probat conversionem f32 → f64 → f32 pondera non mutare.
+/// Teste que la conversion f32 → f64 → f32 ne modifie pas les poids.
+#[test]
+fn probatio_praecisio_conversio() {
+    use super::neuronatus::NeuronatusT;
+
+    let rete = NeuronatusT::<f32>::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 11);
+    let duplex: NeuronatusT<f64> = rete.converte();
+    assert_eq!(duplex.praecisio(), "f64");
+    assert_eq!(duplex.converte::<f32>().strata[0].pesi, rete.strata[0].pesi);
+}
+
+/// Probat praecisionem in archivo scribi et typum discrepantem reici.
+/// Teste que la précision est écrite dans le fichier et qu’un type différent est rejeté.
+#[test]
+fn probatio_praecisio_archivum() {
+    use super::neuronatus::NeuronatusT;
+
+    let rete = NeuronatusT::<f32>::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 11);
+    let textus = serde_json::to_string(&rete).unwrap();
+    assert!(textus.contains("\"praecisio\":\"f32\""));
+    assert!(serde_json::from_str::<NeuronatusT<f32>>(&textus).is_ok());
+    assert!(serde_json::from_str::<NeuronatusT<f64>>(&textus).is_err());
+}
+
+/// Probat idem semen eadem pondera bit pro bit reddere, aliud semen alia.
+/// Teste qu’une même graine donne les mêmes poids bit à bit, une autre graine d’autres poids.
+#[test]
+fn probatio_semen_reproducibile() {
+    use super::neuronatus::Neuronatus;
+
+    let a = Neuronatus::ex_magnitudinibus_ex_semine(&[3, 5, 2], 0.1, 7);
+    let b = Neuronatus::ex_magnitudinibus_ex_semine(&[3, 5, 2], 0.1, 7);
+    let c = Neuronatus::ex_magnitudinibus_ex_semine(&[3, 5, 2], 0.1, 8);
+    for (x, y) in a.strata.iter().zip(&b.strata) {
+        let bits = |t: &Tensor2D| t.elementa.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&x.pesi), bits(&y.pesi));
+        assert_eq!(x.bias, y.bias);
+    }
+    assert_ne!(a.strata[0].pesi, c.strata[0].pesi);
+}
+
+/// Probat strata eiusdem generatoris diversa pondera accipere.
+/// Teste que les couches d’un même générateur reçoivent des poids distincts.
+#[test]
+fn probatio_semen_strata_distincta() {
+    use super::neuronatus::Neuronatus;
+
+    let a = Neuronatus::ex_magnitudinibus_ex_semine(&[3, 5, 2], 0.1, 7);
+    assert_ne!(a.strata[0].pesi.elementa[..5], a.strata[1].pesi.elementa[..5]);
+}
+
+/// Probat strata novi retis pondera Xavier symmetrica et biases nullos accipere.
+/// Teste que les couches d’un nouveau réseau reçoivent des poids Xavier symétriques et des biais nuls.
+#[test]
+fn probatio_initialisatio_praefinita() {
+    use super::neuronatus::Neuronatus;
+
+    let rete = Neuronatus::ex_magnitudinibus_ex_semine(&[20, 30, 10], 0.1, 5);
+    for stratum in &rete.strata {
+        let limes = (6.0 / (stratum.pesi.ordines + stratum.pesi.columnae) as f64).sqrt();
+        assert!(stratum.pesi.minimum() < 0.0 && stratum.pesi.norma_maxima() <= limes);
+        assert!(stratum.bias.materia.iter().all(|&b| b == 0.0));
+    }
+}
+
+/// Probat initialisationem in constructore eligi posse, e.g. rationem uniformem antiquam.
+/// Teste que l’initialisation peut être choisie dans le constructeur, p. ex. l’ancien schéma uniforme.
+#[test]
+fn probatio_initialisatio_electa() {
+    use super::initialisatio::Initialisatio;
+    use super::neuronatus::Neuronatus;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let mut aleator = ChaCha8Rng::seed_from_u64(5);
+    let antiquum = Neuronatus::ex_magnitudinibus_initialisatum_cum(
+        &[3, 4, 1], 0.1, Initialisatio::Uniformis, Initialisatio::Uniformis, &mut aleator,
+    );
+    assert!(antiquum.strata.iter().all(|s| s.pesi.minimum() >= 0.0 && s.bias.materia.iter().any(|&b| b > 0.0)));
+}
+
+/// Data verificationis: intratae normales, exspectata continua, unica (one-hot) et signa in {-1, 1}.
+/// Données de vérification : entrées normales, cibles continues, one-hot et signes dans {-1, 1}.
+fn data_verificationis(aleator: &mut rand_chacha::ChaCha8Rng) -> (Tensor2D, Tensor2D, Tensor2D, Tensor2D) {
+    let intratae = Tensor2D::normalis_cum(3, 5, 0.0, 1.0, aleator);
+    let continuae = Tensor2D::uniformis_cum(3, 5, 0.1, 0.9, aleator);
+    let unicae = Tensor2D::ex_vec(vec![
+        vec![1.0, 0.0, 0.0, 1.0, 0.0],
+        vec![0.0, 1.0, 0.0, 0.0, 1.0],
+        vec![0.0, 0.0, 1.0, 0.0, 0.0],
+    ]);
+    let signa = unicae.map(|x| 2.0 * x - 1.0);
+    (intratae, continuae, unicae, signa)
+}
+
+/// Rete [3, 4, 3] verificationis cum activationibus et perditione datis.
+/// Réseau [3, 4, 3] de vérification avec les activations et la perte données.
+fn rete_verificationis(
+    activationes: [super::activatio::Activatio; 2],
+    perditio: super::perditio::Perditio,
+    aleator: &mut rand_chacha::ChaCha8Rng,
+) -> super::neuronatus::Neuronatus {
+    use super::initialisatio::Initialisatio;
+    use super::stratum::StratumDensum;
+
+    let strata = [(3, 4), (4, 3)]
+        .iter()
+        .zip(activationes)
+        .map(|(&(introitus, exitus), activatio)| {
+            StratumDensum::initialisatus(
+                introitus, exitus, activatio, Initialisatio::XavierNormalis, Initialisatio::XavierNormalis, aleator,
+            )
+        })
+        .collect();
+    super::neuronatus::Neuronatus::ex_stratis(strata, perditio, 0.1)
+}
+
+/// Probat gradientes analyticos omnis activationis, tam in strato occulto quam in ultimo, cum differentiis finitis congruere.
+/// Teste que les gradients analytiques de chaque activation, dans la couche cachée comme dans la dernière, concordent avec les différences finies.
+#[test]
+fn probatio_verificatio_activationum() {
+    use super::activatio::Activatio;
+    use super::perditio::Perditio;
+    use super::verificatio::verifica_gradientes;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let mut aleator = ChaCha8Rng::seed_from_u64(23);
+    let (intratae, continuae, _, _) = data_verificationis(&mut aleator);
+    for activatio in [
+        Activatio::Sigmoides,
+        Activatio::Relu,
+        Activatio::ReluRimosa { alpha: 0.1 },
+        Activatio::Tanh,
+        Activatio::Elu { alpha: 1.0 },
+        Activatio::Gelu,
+        Activatio::Softplus,
+        Activatio::Identitas,
+        Activatio::Softmax,
+    ] {
+        let rete = rete_verificationis([activatio, activatio], Perditio::ErrorQuadraticus, &mut aleator);
+        let relatio = verifica_gradientes(&rete, &intratae, &continuae, 1e-5);
+        assert!(relatio.convenit(1e-6), "{:?}: {}", activatio, relatio);
+        assert_eq!(relatio.parametra, 4 * 3 + 4 + 3 * 4 + 3);
+    }
+}
+
+/// Probat gradientes omnis perditionis, cum activatione ultima et exspectatis idoneis (fusae quoque), congruere.
+/// Teste que les gradients de chaque perte, avec une activation finale et des cibles adaptées (y compris fusionnées), concordent.
+#[test]
+fn probatio_verificatio_perditionum() {
+    use super::activatio::Activatio;
+    use super::perditio::Perditio;
+    use super::verificatio::verifica_gradientes;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let mut aleator = ChaCha8Rng::seed_from_u64(23);
+    let (intratae, continuae, unicae, signa) = data_verificationis(&mut aleator);
+    for (perditio, ultima, exspectatae) in [
+        (Perditio::ErrorQuadraticus, Activatio::Tanh, &continuae),
+        (Perditio::ErrorAbsolutus, Activatio::Identitas, &continuae),
+        (Perditio::Huber { delta: 0.5 }, Activatio::Identitas, &continuae),
+        (Perditio::Cardo, Activatio::Identitas, &signa),
+        (Perditio::EntropiaBinaria, Activatio::Sigmoides, &unicae),
+        (Perditio::EntropiaBinaria, Activatio::Softmax, &continuae),
+        (Perditio::EntropiaCategorica, Activatio::Softmax, &unicae),
+        (Perditio::EntropiaCategorica, Activatio::Sigmoides, &unicae),
+    ] {
+        let rete = rete_verificationis([Activatio::Tanh, ultima], perditio, &mut aleator);
+        let relatio = verifica_gradientes(&rete, &intratae, exspectatae, 1e-5);
+        assert!(relatio.convenit(1e-6), "{:?} cum {:?}: {}", perditio, ultima, relatio);
+    }
+}
+
+/// Probat rete f32 in f64 verificari.
+/// Teste qu’un réseau f32 est vérifié en f64.
+#[test]
+fn probatio_verificatio_f32() {
+    use super::activatio::Activatio;
+    use super::neuronatus::NeuronatusT;
+    use super::perditio::Perditio;
+    use super::verificatio::verifica_gradientes;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let mut aleator = ChaCha8Rng::seed_from_u64(23);
+    let (intratae, _, unicae, _) = data_verificationis(&mut aleator);
+    let rete32: NeuronatusT<f32> =
+        rete_verificationis([Activatio::Gelu, Activatio::Sigmoides], Perditio::EntropiaBinaria, &mut aleator).converte();
+    assert!(verifica_gradientes(&rete32, &intratae.converte(), &unicae.converte(), 1e-5).convenit(1e-6));
+}
+
+/// Rete [2, 3, 2] et minibatch trium exemplorum pro probationibus gradientum.
+/// Réseau [2, 3, 2] et mini-lot de trois exemples pour les tests de gradients.
+fn rete_gradientum() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    let rete = super::neuronatus::Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 2], 0.5, 31);
+    let intratae = Tensor2D::ex_vec(vec![vec![0.1, 0.9, -0.4], vec![0.7, -0.2, 0.3]]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 0.0]]);
+    (rete, intratae, exspectatae)
+}
+
+/// Probat gradientes formam parametrorum sequi.
+/// Teste que les gradients suivent la forme des paramètres.
+#[test]
+fn probatio_gradientes_forma() {
+    let (rete, intratae, exspectatae) = rete_gradientum();
+    let minibatch = rete.gradientes_minibatch(&intratae, &exspectatae);
+    assert_eq!(minibatch.strata.len(), 2);
+    assert_eq!(minibatch.strata[0].pesi.forma(), [3, 2]);
+    assert_eq!(minibatch.strata[1].bias.magnitudo, 2);
+    assert!(minibatch.norma() > 0.0);
+}
+
+/// Probat mediam gradientum singulorum exemplorum gradientem minibatch aequare.
+/// Teste que la moyenne des gradients par exemple égale le gradient du mini-lot.
+#[test]
+fn probatio_gradientes_media() {
+    use super::gradientes::Gradientes;
+
+    let (rete, intratae, exspectatae) = rete_gradientum();
+    let minibatch = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut accumulati = Gradientes::nullus(&rete);
+    for j in 0..3 {
+        let exemplum = rete.gradientes(&intratae.columna(j), &exspectatae.columna(j));
+        accumulati.adde(&exemplum);
+    }
+    accumulati.multiplica(1.0 / 3.0);
+    for (a, b) in accumulati.strata.iter().zip(&minibatch.strata) {
+        assert!((&a.pesi - &b.pesi).norma_maxima() < 1e-12);
+        assert!((&a.bias - &b.bias).materia.iter().all(|d| d.abs() < 1e-12));
+    }
+}
+
+/// Probat instruere_minibatch idem esse ac gradientes deinde applica.
+/// Teste que instruere_minibatch équivaut à gradientes puis applica.
+#[test]
+fn probatio_gradientes_applica() {
+    let (rete, intratae, exspectatae) = rete_gradientum();
+    let minibatch = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut directum = rete.clone();
+    directum.instruere_minibatch(&intratae, &exspectatae);
+    let mut separatum = rete.clone();
+    separatum.applica(&minibatch);
+    assert_eq!(directum.strata[0].pesi, separatum.strata[0].pesi);
+    assert_eq!(directum.status_optimizatoris, separatum.status_optimizatoris);
+}
+
+/// Probat optimizatorem externum statum retis non tangere.
+/// Teste qu’un optimiseur externe ne touche pas l’état du réseau.
+#[test]
+fn probatio_gradientes_optimizator_externus() {
+    use super::optimizator::{Optimizator, StatusOptimizatoris};
+
+    let (rete, intratae, exspectatae) = rete_gradientum();
+    let minibatch = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut externum = rete.clone();
+    let mut status = StatusOptimizatoris::default();
+    externum.applica_cum(&minibatch, Optimizator::adam(), &mut status, 0.01);
+    assert_eq!(status.passus, 1);
+    assert_eq!(externum.status_optimizatoris.passus, 0);
+    assert_ne!(externum.strata[0].pesi, rete.strata[0].pesi);
+}
+
+/// Rete [2, 3, 1] et minibatch cum gradientibus magnis pro probationibus tonsurae.
+/// Réseau [2, 3, 1] et mini-lot aux grands gradients pour les tests d’écrêtage.
+fn rete_tonsurae() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    let rete = super::neuronatus::Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 5);
+    let intratae = Tensor2D::ex_vec(vec![vec![40.0, -30.0], vec![-25.0, 60.0]]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![30.0, -20.0]]);
+    (rete, intratae, exspectatae)
+}
+
+/// Probat tonsuram per valorem omne elementum coercere; norma reddita est ante tonsuram.
+/// Teste que l’écrêtage par valeur borne chaque élément ; la norme renvoyée est celle d’avant.
+#[test]
+fn probatio_tonsura_per_valorem() {
+    use super::gradientes::Tonsura;
+
+    let (rete, intratae, exspectatae) = rete_tonsurae();
+    let gradientes = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut per_valorem = gradientes.clone();
+    assert_eq!(per_valorem.tonde(&Tonsura::per_valorem(0.5)), gradientes.norma());
+    assert!(per_valorem.strata.iter().all(|s| s.pesi.norma_maxima() <= 0.5));
+}
+
+/// Probat tonsuram per normam directionem servare et normam ad limitem redigere.
+/// Teste que l’écrêtage par norme conserve la direction et ramène la norme à la limite.
+#[test]
+fn probatio_tonsura_per_normam() {
+    use super::gradientes::Tonsura;
+
+    let (rete, intratae, exspectatae) = rete_tonsurae();
+    let gradientes = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut per_normam = gradientes.clone();
+    per_normam.tonde(&Tonsura::per_normam(1.0));
+    assert!((per_normam.norma() - 1.0).abs() < 1e-12);
+    let ratio = per_normam.strata[0].pesi[(0, 0)] / gradientes.strata[0].pesi[(0, 0)];
+    assert!((ratio - 1.0 / gradientes.norma()).abs() < 1e-12);
+}
+
+/// Probat gradientes sine tonsura non mutari.
+/// Teste que les gradients ne changent pas sans écrêtage.
+#[test]
+fn probatio_tonsura_nulla() {
+    use super::gradientes::Tonsura;
+
+    let (rete, intratae, exspectatae) = rete_tonsurae();
+    let gradientes = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut intacti = gradientes.clone();
+    intacti.tonde(&Tonsura::default());
+    assert_eq!(intacti, gradientes);
+}
+
+/// Probat tonsuram in disciplina inter gradientes et renovationem applicari.
+/// Teste qu’à l’entraînement l’écrêtage s’applique entre gradients et mise à jour.
+#[test]
+fn probatio_tonsura_in_disciplina() {
+    use super::gradientes::Tonsura;
+
+    let (rete, intratae, exspectatae) = rete_tonsurae();
+    let norma = rete.gradientes_minibatch(&intratae, &exspectatae).norma();
+    let mut tonsum = rete.clone();
+    tonsum.celeritas = 10.0;
+    tonsum.tonsura = Tonsura::per_normam(0.1);
+    tonsum.instruere_minibatch(&intratae, &exspectatae);
+    assert_eq!(tonsum.norma_gradientis, Some(norma));
+    let passus = (&tonsum.strata[0].pesi - &rete.strata[0].pesi).norma_frobenii();
+    assert!(passus <= 10.0 * 0.1 + 1e-12);
+}
+
+/// Probat limitem invalidum in campis publicis positum a `tonde` neglegi.
+/// Teste qu’une limite invalide placée dans les champs publics est ignorée par `tonde`.
+#[test]
+fn probatio_tonsura_invalida_neglecta() {
+    use super::gradientes::Tonsura;
+    use super::neuronatus::Neuronatus;
+
+    let rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 5);
+    let intratae = Tensor2D::ex_vec(vec![vec![0.4, -0.3], vec![-0.2, 0.6]]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![1.0, 0.0]]);
+    let gradientes = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let tonsura = Tonsura { valor_maximus: Some(f64::NAN), norma_maxima: Some(0.0) };
+    assert!(!tonsura.valida());
+    let mut intacti = gradientes.clone();
+    assert_eq!(intacti.tonde(&tonsura), gradientes.norma());
+    assert_eq!(intacti, gradientes);
+}
+
+/// Probat limitem invalidum a disciplina ante initium reici.
+/// Teste qu’une limite invalide est rejetée par disciplina avant le début.
+#[test]
+fn probatio_tonsura_invalida_disciplina() {
+    use super::gradientes::Tonsura;
+    use super::neuronatus::Neuronatus;
+
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 5);
+    rete.tonsura = Tonsura { valor_maximus: Some(f64::NAN), norma_maxima: Some(0.0) };
+    let planum = Tensor3D::ex_elementis(2, 1, 2, vec![0.4, -0.2, -0.3, 0.6]);
+    let exspectata = Tensor3D::ex_elementis(2, 1, 1, vec![1.0, 0.0]);
+    assert!(std::panic::catch_unwind(move || rete.disciplina(&planum, &exspectata, 1, true)).is_err());
+}
+
+/// Duo exempla pro probationibus schedulae in disciplina.
+/// Deux exemples pour les tests de planification pendant disciplina.
+fn exempla_schedulae() -> (Tensor3D, Tensor3D) {
+    let intratae = Tensor3D::ex_vec(vec![vec![vec![0.0, 1.0]], vec![vec![1.0, 0.0]]]);
+    let exspectatae = Tensor3D::ex_vec(vec![vec![vec![1.0]], vec![vec![1.0]]]);
+    (intratae, exspectatae)
+}
+
+/// Probat celeritatem currentem in disciplina renovari et cum rete servari.
+/// Teste que le taux courant est mis à jour pendant disciplina et sauvegardé avec le réseau.
+#[test]
+fn probatio_schedula_in_disciplina() {
+    use super::neuronatus::Neuronatus;
+    use super::schedula::Schedula;
+
+    let (intratae, exspectatae) = exempla_schedulae();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 2, 1], 0.8, 1);
+    rete.schedula = Schedula::gradatim(3);
+    rete.disciplina(&intratae, &exspectatae, 4, true);
+    assert_eq!(rete.status_schedulae.epocha, 4);
+    assert!((rete.celeritas_currens() - 0.08).abs() < 1e-12);
+    let restitutum: Neuronatus = serde_json::from_str(&serde_json::to_string(&rete).unwrap()).unwrap();
+    assert_eq!(restitutum.status_schedulae, rete.status_schedulae);
+    assert_eq!(restitutum.schedula, rete.schedula);
+}
+
+/// Probat sine schedula activa celeritatem mutatam post disciplinam statim valere.
+/// Teste que sans planification active un taux modifié après disciplina s’applique aussitôt.
+#[test]
+fn probatio_schedula_constans() {
+    use super::neuronatus::Neuronatus;
+    use super::schedula::Schedula;
+
+    let (intratae, exspectatae) = exempla_schedulae();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 2, 1], 0.8, 1);
+    rete.schedula = Schedula::gradatim(3);
+    rete.disciplina(&intratae, &exspectatae, 4, true);
+    rete.schedula = Schedula::Constans;
+    assert!((rete.celeritas_currens() - 0.8).abs() < 1e-12);
+    rete.disciplina(&intratae, &exspectatae, 2, true);
+    rete.celeritas = 0.3;
+    assert!((rete.celeritas_currens() - 0.3).abs() < 1e-12);
+}
+
+/// Rete [2, 3, 1] et minibatch duorum exemplorum pro probationibus regularizationis.
+/// Réseau [2, 3, 1] et mini-lot de deux exemples pour les tests de régularisation.
+fn rete_regularizationis() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    let rete = super::neuronatus::Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 13);
+    let intratae = Tensor2D::ex_vec(vec![vec![0.2, 0.8], vec![0.6, -0.4]]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![1.0, 0.0]]);
+    (rete, intratae, exspectatae)
+}
+
+/// Probat poenam in perditione: λ₁·Σ|w| + (λ₂/2)·Σw², biasibus exclusis; perditio datorum eam non includit.
+/// Teste la pénalité dans la perte : λ₁·Σ|w| + (λ₂/2)·Σw², biais exclus ; la perte des données ne l’inclut pas.
+#[test]
+fn probatio_regularizatio_perditio() {
+    use super::regularizatio::Regularizatio;
+
+    let (mut rete, intratae, exspectatae) = rete_regularizationis();
+    let pura = rete.perditio_minibatch(&intratae, &exspectatae);
+    rete.regularizatio = Regularizatio { l1: 0.01, l2: 0.1, bias: false };
+    let (summa_abs, summa_quadrata) = rete.strata.iter().fold((0.0, 0.0), |(a, q), s| {
+        (a + s.pesi.norma_l1(), q + s.pesi.norma_frobenii().powi(2))
+    });
+    let exspectata = pura + 0.01 * summa_abs + 0.05 * summa_quadrata;
+    assert!((rete.perditio_minibatch(&intratae, &exspectatae) - exspectata).abs() < 1e-12);
+    let batch_in = Tensor3D::ex_vec(vec![vec![vec![0.2, 0.6]], vec![vec![0.8, -0.4]]]);
+    let batch_ex = Tensor3D::ex_vec(vec![vec![vec![1.0]], vec![vec![0.0]]]);
+    assert!((rete.perditio_batch(&batch_in, &batch_ex) - exspectata).abs() < 1e-12);
+    assert!((rete.perditio_datorum(&batch_in, &batch_ex) - pura).abs() < 1e-12);
+}
+
+/// Probat gradientem poenae cum differentiis finitis congruere, etiam cum biasibus.
+/// Teste que le gradient de la pénalité concorde avec les différences finies, biais compris.
+#[test]
+fn probatio_regularizatio_gradientes() {
+    use super::regularizatio::Regularizatio;
+    use super::verificatio::verifica_gradientes;
+
+    let (mut rete, intratae, exspectatae) = rete_regularizationis();
+    rete.regularizatio = Regularizatio { l1: 0.01, l2: 0.1, bias: false };
+    assert!(verifica_gradientes(&rete, &intratae, &exspectatae, 1e-6).convenit(1e-6));
+    rete.regularizatio.bias = true;
+    assert!(verifica_gradientes(&rete, &intratae, &exspectatae, 1e-6).convenit(1e-6));
+}
+
+/// Probat biasibus sine regularizatione gradientem biasis non mutari, ponderum λ₂·w addi.
+/// Teste que sans régularisation des biais leur gradient est inchangé, celui des poids augmenté de λ₂·w.
+#[test]
+fn probatio_regularizatio_bias() {
+    use super::regularizatio::Regularizatio;
+
+    let (mut rete, intratae, exspectatae) = rete_regularizationis();
+    rete.regularizatio = Regularizatio::l2(1.0);
+    let mut pura_rete = rete.clone();
+    pura_rete.regularizatio = Regularizatio::default();
+    let (g, h) = (rete.gradientes_minibatch(&intratae, &exspectatae), pura_rete.gradientes_minibatch(&intratae, &exspectatae));
+    assert_eq!(g.strata[1].bias, h.strata[1].bias);
+    assert!((&(&g.strata[1].pesi - &h.strata[1].pesi) - &rete.strata[1].pesi).norma_maxima() < 1e-12);
+}
+
+/// Rete [2, 64, 1] cum omissione 0.5 et minibatch trium exemplorum.
+/// Réseau [2, 64, 1] avec un dropout de 0.5 et mini-lot de trois exemples.
+fn rete_omissionis() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    let intratae = Tensor2D::ex_vec(vec![vec![0.2, 0.8, 0.5], vec![0.6, -0.4, 0.1]]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![1.0, 0.0, 1.0]]);
+    let mut rete = super::neuronatus::Neuronatus::ex_magnitudinibus_ex_semine(&[2, 64, 1], 0.5, 17);
+    rete.pone_omissionem(0.5);
+    (rete, intratae, exspectatae)
+}
+
+/// Probat omissionem solum in stratis occultis poni.
+/// Teste que le dropout n’est placé que sur les couches cachées.
+#[test]
+fn probatio_omissio_strata_occulta() {
+    let (rete, _, _) = rete_omissionis();
+    assert_eq!(rete.strata[0].omissio, 0.5);
+    assert_eq!(rete.strata[1].omissio, 0.0);
+}
+
+/// Probat praedictionem omissionem ignorare et determinatam manere, etiam per magistrum.
+/// Teste que la prédiction ignore le dropout et reste déterministe, y compris via le magister.
+#[test]
+fn probatio_omissio_praedictio() {
+    use super::magister::MagisterNervorum;
+
+    let (mut rete, intratae, _) = rete_omissionis();
+    let cum = rete.praedictio_minibatch(&intratae);
+    rete.pone_omissionem(0.0);
+    assert_eq!(rete.praedictio_minibatch(&intratae), cum);
+
+    rete.pone_omissionem(0.5);
+    let mut magister = MagisterNervorum::novus();
+    magister.adde("omissio", rete.clone());
+    let inputum = Tensor1D::ex_vec(vec![0.2, 0.6]);
+    assert_eq!(magister.praedictio("omissio", &inputum), Some(rete.praedictio(&inputum)));
+}
+
+/// Probat cum generatore gradientes a larva pendere; idem semen eosdem reddit.
+/// Teste qu’avec un générateur les gradients dépendent du masque ; la même graine les reproduit.
+#[test]
+fn probatio_omissio_semen() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let (rete, intratae, exspectatae) = rete_omissionis();
+    let mut aleator = ChaCha8Rng::seed_from_u64(3);
+    let (primi, _) = rete.gradientes_minibatch_cum(&intratae, &exspectatae, Some(&mut aleator));
+    let (iterum, _) = rete.gradientes_minibatch_cum(&intratae, &exspectatae, Some(&mut ChaCha8Rng::seed_from_u64(3)));
+    assert_eq!(iterum, primi);
+    assert_ne!(rete.gradientes_minibatch_cum(&intratae, &exspectatae, Some(&mut aleator)).0, primi);
+}
+
+/// Probat sine generatore gradientes omissionem omittere.
+/// Teste que sans générateur les gradients ignorent le dropout.
+#[test]
+fn probatio_omissio_sine_generatore() {
+    use super::neuronatus::Modus;
+
+    let (rete, intratae, exspectatae) = rete_omissionis();
+    let mut inferentia = rete.clone();
+    inferentia.modus = Modus::Inferentia;
+    assert_eq!(rete.gradientes_minibatch(&intratae, &exspectatae), inferentia.gradientes_minibatch(&intratae, &exspectatae));
+}
+
+/// Probat instruere generatorem seminis retis adhibere, ut disciplina reproducibilis sit.
+/// Teste que instruere utilise le générateur de la graine du réseau, pour un entraînement reproductible.
+#[test]
+fn probatio_omissio_instruere() {
+    let (rete, intratae, exspectatae) = rete_omissionis();
+    let (mut primum, mut alterum) = (rete.clone(), rete.clone());
+    primum.instruere_minibatch(&intratae, &exspectatae);
+    alterum.instruere_minibatch(&intratae, &exspectatae);
+    assert_eq!(primum.strata[0].pesi, alterum.strata[0].pesi);
+}
+
+/// Probat, ponderibus ultimi strati nullis (δ a larva non pendet), unitates omissas gradientem
+/// nullum accipere, ceteras exacte 1 / (1 − p) = 2 vicibus maiorem.
+/// Teste qu’avec des poids de sortie nuls (δ ne dépend pas du masque), les unités omises reçoivent
+/// un gradient nul, les autres un gradient exactement 1 / (1 − p) = 2 fois plus grand.
+#[test]
+fn probatio_omissio_scala() {
+    use super::neuronatus::Modus;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let (mut rete, intratae, exspectatae) = rete_omissionis();
+    let mut aleator = ChaCha8Rng::seed_from_u64(3);
+    let (unum_in, unum_ex) = (intratae.seca_columnas(0..1), exspectatae.seca_columnas(0..1));
+    rete.strata[1].pesi = Tensor2D::nullus(1, 64);
+    let mut inferentia = rete.clone();
+    inferentia.modus = Modus::Inferentia;
+    let plena = inferentia.gradientes_minibatch(&unum_in, &unum_ex);
+    assert_eq!(inferentia.gradientes_minibatch_cum(&unum_in, &unum_ex, Some(&mut aleator)).0, plena);
+    let (omissa, _) = rete.gradientes_minibatch_cum(&unum_in, &unum_ex, Some(&mut aleator));
+    let mut omissae = 0;
+    for j in 0..64 {
+        let (g, h) = (omissa.strata[1].pesi[(0, j)], plena.strata[1].pesi[(0, j)]);
+        if g == 0.0 {
+            omissae += 1;
+        } else {
+            assert!((g - 2.0 * h).abs() < 1e-12);
+        }
+    }
+    assert!(omissae > 10 && omissae < 54);
+}
+
+/// Probat verificationem gradientum etiam cum omissione posita convenire: `gradientes_minibatch` larvas non adhibet.
+/// Teste que la vérification des gradients concorde même avec un dropout configuré : `gradientes_minibatch` n’applique aucun masque.
+#[test]
+fn probatio_omissio_verificatio() {
+    use super::verificatio::verifica_gradientes;
+
+    let (rete, intratae, exspectatae) = rete_omissionis();
+    assert!(verifica_gradientes(&rete, &intratae, &exspectatae, 1e-6).convenit(1e-6));
+}
+
+/// Probat probabilitatem omissionis extra [0, 1) a `pone_omissionem` reici.
+/// Teste qu’une probabilité de dropout hors de [0, 1) est rejetée par `pone_omissionem`.
+#[test]
+fn probatio_omissio_invalida() {
+    use super::neuronatus::Neuronatus;
+
+    let rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 17);
+    for p in [1.0, -0.1, f64::NAN] {
+        let mut invalidum = rete.clone();
+        assert!(std::panic::catch_unwind(move || invalidum.pone_omissionem(p)).is_err());
+    }
+}
+
+/// Probat probabilitatem omissionis extra [0, 1) in archivo retis reici.
+/// Teste qu’une probabilité de dropout hors de [0, 1) est rejetée dans le fichier d’un réseau.
+#[test]
+fn probatio_omissio_archivum_invalidum() {
+    use super::neuronatus::Neuronatus;
+
+    let rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.5, 17);
+    let mut json: serde_json::Value = serde_json::to_value(&rete).unwrap();
+    json["strata"][0]["omissio"] = serde_json::json!(1.5);
+    assert!(serde_json::from_value::<Neuronatus>(json.clone()).is_err());
+    json["strata"][0]["omissio"] = serde_json::json!(0.5);
+    assert_eq!(serde_json::from_value::<Neuronatus>(json).unwrap().strata[0].omissio, 0.5);
+}
+
+/// Rete [3, 5, 4, 2] cum normalizatione per batch et minibatch quattuor exemplorum.
+/// Réseau [3, 5, 4, 2] avec normalisation par lot et mini-lot de quatre exemples.
+fn rete_normalizationis() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    use super::normalizatio::Normalizatio;
+
+    let intratae = Tensor2D::ex_vec(vec![
+        vec![0.2, 0.8, 0.5, -0.3],
+        vec![0.6, -0.4, 0.1, 0.9],
+        vec![1.5, 0.3, -0.7, 0.2],
+    ]);
+    let exspectatae = Tensor2D::ex_vec(vec![vec![1.0, 0.0, 1.0, 0.0], vec![0.0, 1.0, 0.5, 0.2]]);
+    let mut rete = super::neuronatus::Neuronatus::ex_magnitudinibus_ex_semine(&[3, 5, 4, 2], 0.1, 23);
+    rete.pone_normalizationem(Normalizatio::batch);
+    (rete, intratae, exspectatae)
+}
+
+/// Idem rete post trecentas renovationes minibatch.
+/// Le même réseau après trois cents mises à jour par mini-lot.
+fn rete_normalizationis_exercitatum() -> (super::neuronatus::Neuronatus, Tensor2D, Tensor2D) {
+    let (mut rete, intratae, exspectatae) = rete_normalizationis();
+    for _ in 0..300 {
+        rete.instruere_minibatch(&intratae, &exspectatae);
+    }
+    (rete, intratae, exspectatae)
+}
+
+/// Probat normalizationem in stratis occultis tantum poni.
+/// Teste que la normalisation n’est placée que sur les couches cachées.
+#[test]
+fn probatio_normalizatio_strata_occulta() {
+    let (rete, _, _) = rete_normalizationis();
+    assert!(rete.strata[0].normalizatio.is_some() && rete.strata[1].normalizatio.is_some());
+    assert!(rete.strata[2].normalizatio.is_none());
+}
+
+/// Probat summas strati normatas per batch quamque unitatem mediam 0 et variantiam ≈ 1 habere.
+/// Teste que les sommes normalisées d’une couche ont, par lot, une moyenne 0 et une variance ≈ 1 par unité.
+#[test]
+fn probatio_normalizatio_summa_normata() {
+    let (rete, intratae, _) = rete_normalizationis();
+    let (normata, _) = rete.strata[0].summa_normata(&intratae, true);
+    for i in 0..normata.ordines {
+        let ordo = normata.ordo(i);
+        let media = ordo.iter().sum::<f64>() / 4.0;
+        let variantia = ordo.iter().map(|x| (x - media).powi(2)).sum::<f64>() / 4.0;
+        assert!(media.abs() < 1e-12 && (variantia - 1.0).abs() < 1e-3);
+    }
+}
+
+/// Probat summas strati normatas per stratum quodque exemplum mediam 0 habere.
+/// Teste que les sommes normalisées d’une couche ont, par couche, une moyenne 0 par exemple.
+#[test]
+fn probatio_normalizatio_strati_summa_normata() {
+    use super::normalizatio::Normalizatio;
+
+    let (rete, intratae, _) = rete_normalizationis();
+    let (normata, _) = rete.strata[0].clone().cum_normalizatione(Normalizatio::strati(5)).summa_normata(&intratae, true);
+    for j in 0..normata.columnae {
+        assert!(normata.columna(j).materia.iter().sum::<f64>().abs() < 1e-12);
+    }
+}
+
+/// Probat gradientes γ, β et ponderum cum differentiis finitis congruere, in disciplina et inferentia.
+/// Teste que les gradients de γ, β et des poids concordent avec les différences finies, à l’entraînement et en inférence.
+#[test]
+fn probatio_normalizatio_verificatio() {
+    use super::neuronatus::Modus;
+    use super::verificatio::verifica_gradientes;
+
+    let (rete, intratae, exspectatae) = rete_normalizationis();
+    let relatio = verifica_gradientes(&rete, &intratae, &exspectatae, 1e-6);
+    assert!(relatio.convenit(1e-6), "{}", relatio);
+    assert_eq!(relatio.parametra, 3 * 5 + 5 + 5 * 4 + 4 + 4 * 2 + 2 + 2 * (5 + 4));
+    let mut inferentia = rete.clone();
+    inferentia.modus = Modus::Inferentia;
+    assert!(verifica_gradientes(&inferentia, &intratae, &exspectatae, 1e-6).convenit(1e-6));
+}
+
+/// Probat gradientes normalizationis per stratum cum differentiis finitis congruere.
+/// Teste que les gradients de la normalisation par couche concordent avec les différences finies.
+#[test]
+fn probatio_normalizatio_strati_verificatio() {
+    use super::normalizatio::Normalizatio;
+    use super::verificatio::verifica_gradientes;
+
+    let (mut strati, intratae, exspectatae) = rete_normalizationis();
+    strati.pone_normalizationem(Normalizatio::strati);
+    assert!(verifica_gradientes(&strati, &intratae, &exspectatae, 1e-6).convenit(1e-6));
+}
+
+/// Probat gradientes statisticas currentes non mutare et γ, β solum in stratis normatis continere.
+/// Teste que les gradients ne modifient pas les statistiques courantes et ne contiennent γ, β que pour les couches normalisées.
+#[test]
+fn probatio_normalizatio_gradientes() {
+    use super::gradientes::Gradientes;
+
+    let (rete, intratae, exspectatae) = rete_normalizationis();
+    let ante = rete.strata[0].normalizatio.clone();
+    let (gradientes, _) = rete.gradientes_minibatch_cum(&intratae, &exspectatae, None);
+    assert_eq!(rete.gradientes_minibatch(&intratae, &exspectatae), gradientes);
+    assert_eq!(rete.strata[0].normalizatio, ante);
+    assert_eq!(Gradientes::nullus(&rete).strata[0].normalizatio.as_ref().unwrap().scala.magnitudo, 5);
+    assert!(gradientes.strata[1].normalizatio.is_some() && gradientes.strata[2].normalizatio.is_none());
+}
+
+/// Probat statisticas redditas currentes renovare: μ ← 0.9·μ + 0.1·μ_batch.
+/// Teste que les statistiques renvoyées mettent à jour les courantes : μ ← 0.9·μ + 0.1·μ_lot.
+#[test]
+fn probatio_normalizatio_renova_statisticas() {
+    use super::normalizatio::Normalizatio;
+
+    let (mut rete, intratae, exspectatae) = rete_normalizationis();
+    let media_batch = rete.strata[0].summa(&intratae).media_ordinum();
+    let (_, statisticae) = rete.gradientes_minibatch_cum(&intratae, &exspectatae, None);
+    rete.renova_statisticas(&statisticae);
+    let Some(Normalizatio::Batch { media_currens, .. }) = &rete.strata[0].normalizatio else { unreachable!() };
+    assert!((media_currens - &media_batch.multiplica_per_scalar(0.1)).materia.iter().all(|d| d.abs() < 1e-12));
 }
 
+/// Probat gradientes in inferentia (statisticis currentibus) ab iis disciplinae differre.
+/// Teste que les gradients en inférence (statistiques courantes) diffèrent de ceux de l’entraînement.
+#[test]
+fn probatio_normalizatio_gradientes_inferentiae() {
+    use super::neuronatus::Modus;
+
+    let (rete, intratae, exspectatae) = rete_normalizationis();
+    let mut inferentia = rete.clone();
+    inferentia.modus = Modus::Inferentia;
+    assert!(inferentia.gradientes_minibatch(&intratae, &exspectatae) != rete.gradientes_minibatch(&intratae, &exspectatae));
+}
+
+/// Probat praedictionem statisticas currentes adhibere: exemplum non pendet ex ceteris minibatch.
+/// Teste que la prédiction utilise les statistiques courantes : un exemple ne dépend pas du reste du mini-lot.
+#[test]
+fn probatio_normalizatio_praedictio() {
+    let (mut rete, intratae, exspectatae) = rete_normalizationis();
+    let (_, statisticae) = rete.gradientes_minibatch_cum(&intratae, &exspectatae, None);
+    rete.renova_statisticas(&statisticae);
+    let primum = Tensor1D::ex_vec(vec![0.2, 0.6, 1.5]);
+    let singulum = rete.praedictio(&primum);
+    let in_batch = rete.praedictio_minibatch(&intratae).columna(0);
+    assert!((&singulum - &in_batch).materia.iter().all(|d| d.abs() < 1e-12));
+}
+
+/// Probat disciplinam cum normalizatione perditionem minuere et γ discere.
+/// Teste que l’entraînement avec normalisation diminue la perte et apprend γ.
+#[test]
+fn probatio_normalizatio_disciplina_minuit() {
+    let (rete, intratae, exspectatae) = rete_normalizationis();
+    let ante = rete.perditio_minibatch(&intratae, &exspectatae);
+    let (exercitatum, _, _) = rete_normalizationis_exercitatum();
+    assert!(exercitatum.perditio_minibatch(&intratae, &exspectatae) < ante);
+    assert!(exercitatum.strata[0].normalizatio.as_ref().unwrap().scala().materia.iter().any(|&g| g != 1.0));
+}
+
+/// Probat γ, β, statisticas et statum optimizatoris cum rete serializari.
+/// Teste que γ, β, les statistiques et l’état de l’optimiseur sont sérialisés avec le réseau.
+#[test]
+fn probatio_normalizatio_serializatio() {
+    use super::neuronatus::Neuronatus;
+    use super::normalizatio::Normalizatio;
+
+    let (rete, _, _) = rete_normalizationis_exercitatum();
+    let restitutum: Neuronatus = serde_json::from_str(&serde_json::to_string(&rete).unwrap()).unwrap();
+    let prope = |a: &Tensor1D, b: &Tensor1D| (a - b).materia.iter().all(|d| d.abs() < 1e-12);
+    match (&restitutum.strata[0].normalizatio, &rete.strata[0].normalizatio) {
+        (
+            Some(Normalizatio::Batch { scala: a, media_currens: m, variantia_currens: v, .. }),
+            Some(Normalizatio::Batch { scala: b, media_currens: n, variantia_currens: w, .. }),
+        ) => assert!(prope(a, b) && prope(m, n) && prope(v, w)),
+        _ => panic!("Normalizatio per batch non restituta."),
+    }
+    assert_eq!(restitutum.status_optimizatoris.strata[0].scala.primum.len(), 5);
+    let primum = Tensor1D::ex_vec(vec![0.2, 0.6, 1.5]);
+    assert!(prope(&restitutum.praedictio(&primum), &rete.praedictio(&primum)));
+}
+
+/// Probat normalizationem in conversione f32 servari.
+/// Teste que la normalisation est conservée par la conversion en f32.
+#[test]
+fn probatio_normalizatio_conversio() {
+    use super::neuronatus::NeuronatusT;
+
+    let (rete, _, _) = rete_normalizationis();
+    let f32_rete: NeuronatusT<f32> = rete.converte();
+    assert_eq!(f32_rete.strata[1].normalizatio.as_ref().map(|n| n.magnitudo()), Some(4));
+}
+
+/// Probat minibatch unius exempli in disciplina statisticas currentes adhibere nec renovare.
+/// Teste qu’un mini-lot d’un seul exemple utilise à l’entraînement les statistiques courantes sans les mettre à jour.
+#[test]
+fn probatio_normalizatio_exemplum_singulum() {
+    use super::normalizatio::Normalizatio;
+    use super::verificatio::verifica_gradientes;
+
+    let (rete, intratae, exspectatae) = rete_normalizationis_exercitatum();
+    let (unum_in, unum_ex) = (intratae.seca_columnas(0..1), exspectatae.seca_columnas(0..1));
+    assert_eq!(rete.perditio_minibatch_disciplinae(&unum_in, &unum_ex), rete.perditio_minibatch(&unum_in, &unum_ex));
+    assert!(verifica_gradientes(&rete, &unum_in, &unum_ex, 1e-6).convenit(1e-6));
+    let mut singulum = rete.clone();
+    singulum.instruere_minibatch(&unum_in, &unum_ex);
+    assert_ne!(singulum.strata[0].pesi, rete.strata[0].pesi);
+    match (&singulum.strata[0].normalizatio, &rete.strata[0].normalizatio) {
+        (
+            Some(Normalizatio::Batch { media_currens: m, variantia_currens: v, .. }),
+            Some(Normalizatio::Batch { media_currens: n, variantia_currens: w, .. }),
+        ) => assert!(m == n && v == w),
+        _ => panic!("Normalizatio per batch amissa."),
+    }
+}
+
+/// Exempla quattuor pro disciplina cum normalizatione per batch.
+/// Quatre exemples pour disciplina avec normalisation par lot.
+fn exempla_normalizationis() -> (Tensor3D, Tensor3D) {
+    let intratae = Tensor3D::ex_elementis(4, 1, 2, vec![0.2, 0.6, 0.8, -0.4, 0.5, 0.1, -0.3, 0.9]);
+    let exspectatae = Tensor3D::ex_elementis(4, 1, 1, vec![1.0, 0.0, 1.0, 0.0]);
+    (intratae, exspectatae)
+}
+
+/// Probat statisticas currentes normalizationis per batch per `disciplina` renovari.
+/// Teste que `disciplina` met à jour les statistiques courantes de la normalisation par lot.
+#[test]
+fn probatio_normalizatio_disciplina() {
+    use super::neuronatus::{Neuronatus, MAGNITUDO_BATCH_NORMALIZATIONIS};
+    use super::normalizatio::Normalizatio;
+
+    let (intratae, exspectatae) = exempla_normalizationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.1, 29);
+    rete.pone_normalizationem(Normalizatio::batch);
+    assert_eq!(rete.magnitudo_batch, MAGNITUDO_BATCH_NORMALIZATIONIS);
+
+    rete.disciplina(&intratae, &exspectatae, 5, true);
+    let Some(Normalizatio::Batch { media_currens, variantia_currens, .. }) = &rete.strata[0].normalizatio else {
+        unreachable!()
+    };
+    assert!(media_currens.materia.iter().all(|&m| m != 0.0));
+    assert!(variantia_currens.materia.iter().all(|&v| v != 1.0));
+}
+
+/// Probat minibatch unius exempli, quod statisticas currentes numquam renovaret, reici.
+/// Teste qu’un mini-lot d’un seul exemple, qui ne mettrait jamais à jour les statistiques courantes, est rejeté.
+#[test]
+fn probatio_normalizatio_batch_singulum() {
+    use super::neuronatus::Neuronatus;
+    use super::normalizatio::Normalizatio;
+
+    let (intratae, exspectatae) = exempla_normalizationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[2, 3, 1], 0.1, 29);
+    rete.pone_normalizationem(Normalizatio::batch);
+    rete.magnitudo_batch = 1;
+    assert!(std::panic::catch_unwind(move || rete.disciplina(&intratae, &exspectatae, 1, true)).is_err());
+}
+
+/// Exempla unius valoris: intratae x et exspectata t ut tensores [n x 1 x 1].
+/// Exemples à une valeur : entrées x et cibles t en tenseurs [n x 1 x 1].
+fn exempla_plana(x: &[f64], t: &[f64]) -> (Tensor3D, Tensor3D) {
+    let planum = |v: &[f64]| Tensor3D::ex_elementis(v.len(), 1, 1, v.to_vec());
+    (planum(x), planum(t))
+}
+
+/// Exempla disciplinae et validationis contrariae (eadem intratae, exspectata fere inversa).
+/// Exemples d’entraînement et de validation contraire (mêmes entrées, cibles presque inversées).
+fn exempla_validationis() -> ((Tensor3D, Tensor3D), (Tensor3D, Tensor3D)) {
+    (
+        exempla_plana(&[1.0, -1.0, 2.0, -3.0, 0.5], &[1.0, 0.0, 0.0, 0.0, 1.0]),
+        exempla_plana(&[1.0, -1.0, 2.0, -3.0, 0.5], &[0.0, 1.0, 1.0, 1.0, 0.0]),
+    )
+}
+
+/// Probat accuratiam exitus singuli per limen 0.5 computari.
+/// Teste que l’exactitude d’une sortie unique est calculée au seuil 0.5.
+#[test]
+fn probatio_accuratio_limen() {
+    use super::neuronatus::Neuronatus;
+
+    let ((intratae, exspectatae), _) = exempla_validationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 1], 0.5, 1);
+    rete.strata[0].pesi = Tensor2D::ex_vec(vec![vec![10.0]]);
+    rete.strata[0].bias.materia[0] = 0.0;
+    assert_eq!(rete.accuratio_batch(&intratae, &exspectatae), 0.8);
+}
+
+/// Probat accuratiam plurium exituum per argmax computari.
+/// Teste que l’exactitude de plusieurs sorties est calculée par argmax.
+#[test]
+fn probatio_accuratio_argmax() {
+    use super::neuronatus::Neuronatus;
+
+    let mut duplex = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 2], 0.5, 1);
+    duplex.strata[0].pesi = Tensor2D::ex_vec(vec![vec![1.0], vec![-1.0]]);
+    duplex.strata[0].bias.materia = vec![0.0, 0.0];
+    let classes = Tensor3D::ex_elementis(3, 1, 2, vec![1.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    let duplex_in = Tensor3D::ex_elementis(3, 1, 1, vec![2.0, -1.0, -4.0]);
+    assert!((duplex.accuratio_batch(&duplex_in, &classes) - 2.0 / 3.0).abs() < 1e-12);
+}
+
+/// Probat perditionem Cardo, exspectatis in {-1, 1}, limen accuratiae 0 habere.
+/// Teste que la perte Cardo, aux cibles dans {-1, 1}, a un seuil d’exactitude de 0.
+#[test]
+fn probatio_accuratio_cardo() {
+    use super::activatio::Activatio;
+    use super::neuronatus::Neuronatus;
+    use super::perditio::Perditio;
+
+    let ((intratae, _), _) = exempla_validationis();
+    let strata = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 1], 0.5, 1).strata;
+    let mut cardo = Neuronatus::ex_stratis(strata, Perditio::Cardo, 0.5);
+    cardo.strata[0].activatio = Activatio::Identitas;
+    cardo.strata[0].pesi = Tensor2D::ex_vec(vec![vec![0.1]]);
+    cardo.strata[0].bias.materia[0] = 0.0;
+    let signa = Tensor3D::ex_elementis(5, 1, 1, vec![1.0, -1.0, 1.0, -1.0, -1.0]);
+    assert_eq!(cardo.accuratio_batch(&intratae, &signa), 0.8);
+}
+
+/// Probat validationem contrariam: perditio validationis crescit, disciplina post patientiam sistitur
+/// et pondera epochae optimae restituuntur.
+/// Teste la validation contraire : la perte de validation augmente, l’entraînement s’arrête après la patience
+/// et les poids de la meilleure époque sont restaurés.
+#[test]
+fn probatio_validatio_cessatio() {
+    use super::neuronatus::Neuronatus;
+    use super::validatio::{Cessatio, Validatio};
+
+    let ((intratae, exspectatae), (vi, ve)) = exempla_validationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 4, 1], 2.0, 7);
+    rete.cessatio = Cessatio::patientia(3);
+    let validatio = Validatio::Exempla { intratae: &vi, exspectatae: &ve };
+    let historia = rete.disciplina_validata(&intratae, &exspectatae, validatio, 200, true);
+    let optima = *historia.mensura_optima().unwrap();
+    assert!(historia.interrupta && historia.restituta);
+    assert_eq!(historia.epochae.len(), historia.optima.unwrap() + 4);
+    assert_eq!(rete.perditio_datorum(&vi, &ve), optima.perditio_validationis);
+    assert_eq!(rete.accuratio_batch(&vi, &ve), optima.accuratio_validationis);
+    let ultima = historia.epochae.last().unwrap();
+    assert!(ultima.perditio_validationis >= optima.perditio_validationis);
+    assert!(ultima.perditio < historia.epochae[0].perditio);
+}
+
+/// Probat sine patientia omnes epochas fieri et sine restitutione pondera ultima manere.
+/// Teste que sans patience toutes les époques sont effectuées et que sans restauration les derniers poids restent.
+#[test]
+fn probatio_validatio_sine_restitutione() {
+    use super::neuronatus::Neuronatus;
+    use super::validatio::{Cessatio, Criterium, Validatio};
+
+    let ((intratae, exspectatae), (vi, ve)) = exempla_validationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 4, 1], 2.0, 7);
+    rete.cessatio = Cessatio { restitue_optima: false, criterium: Criterium::Accuratio, ..Cessatio::default() };
+    let validatio = Validatio::Exempla { intratae: &vi, exspectatae: &ve };
+    let historia = rete.disciplina_validata(&intratae, &exspectatae, validatio, 20, true);
+    assert_eq!(historia.epochae.len(), 20);
+    assert!(!historia.interrupta && !historia.restituta);
+    assert_eq!(rete.perditio_datorum(&vi, &ve), historia.epochae[19].perditio_validationis);
+}
+
+/// Probat perditionem validationis poenam L1/L2 non includere.
+/// Teste que la perte de validation n’inclut pas la pénalité L1/L2.
+#[test]
+fn probatio_validatio_sine_poena() {
+    use super::neuronatus::Neuronatus;
+    use super::regularizatio::Regularizatio;
+    use super::validatio::Validatio;
+
+    let ((intratae, exspectatae), (vi, ve)) = exempla_validationis();
+    let mut regulatum = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 4, 1], 2.0, 7);
+    regulatum.regularizatio = Regularizatio::l2(0.5);
+    let validatio = Validatio::Exempla { intratae: &vi, exspectatae: &ve };
+    let historia = regulatum.disciplina_validata(&intratae, &exspectatae, validatio, 3, true);
+    let optima = historia.mensura_optima().unwrap().perditio_validationis;
+    assert_eq!(regulatum.perditio_datorum(&vi, &ve), optima);
+    assert!(regulatum.perditio_batch(&vi, &ve) > optima);
+}
+
+/// Probat fractionem validationis per disciplina_validata et cessationem cum rete serializari.
+/// Teste la fraction de validation via disciplina_validata et la sérialisation de l’arrêt anticipé avec le réseau.
+#[test]
+fn probatio_validatio_fractio() {
+    use super::neuronatus::Neuronatus;
+    use super::validatio::{Cessatio, Criterium, Validatio};
+
+    let ((intratae, exspectatae), _) = exempla_validationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 4, 1], 2.0, 7);
+    rete.cessatio = Cessatio { restitue_optima: false, criterium: Criterium::Accuratio, ..Cessatio::default() };
+    let historia = rete.disciplina_validata(&intratae, &exspectatae, Validatio::Fractio(0.4), 5, true);
+    assert_eq!(historia.epochae.len(), 5);
+    let restitutum: Neuronatus = serde_json::from_str(&serde_json::to_string(&rete).unwrap()).unwrap();
+    assert_eq!(restitutum.cessatio, rete.cessatio);
+}
+
+/// Probat restitutionem epochae optimae statum optimizatoris et schedulae quoque restituere.
+/// Teste que la restauration de la meilleure époque restaure aussi l’état de l’optimiseur et de la planification.
+#[test]
+fn probatio_restitutio_status() {
+    use super::neuronatus::Neuronatus;
+    use super::optimizator::Optimizator;
+    use super::schedula::Schedula;
+    use super::validatio::{Cessatio, Validatio};
+
+    let ((intratae, exspectatae), (vi, ve)) = exempla_validationis();
+    let mut rete = Neuronatus::ex_magnitudinibus_ex_semine(&[1, 4, 1], 0.5, 7);
+    rete.pone_optimizatorem(Optimizator::adam());
+    rete.schedula = Schedula::Exponentialis { gamma: 0.9 };
+    rete.cessatio = Cessatio::patientia(3);
+    let validatio = Validatio::Exempla { intratae: &vi, exspectatae: &ve };
+    let historia = rete.disciplina_validata(&intratae, &exspectatae, validatio, 200, true);
+    assert!(historia.restituta && historia.epochae.len() > historia.optima.unwrap() + 1);
+
+    // Quinque renovationes per epocham (magnitudo batch 1); schedula post epocham optimam pergit.
+    // Cinq mises à jour par époque (taille de lot 1) ; la planification reprend après la meilleure époque.
+    let optima = historia.mensura_optima().unwrap();
+    assert_eq!(rete.status_optimizatoris.passus, 5 * (optima.epocha as u64 + 1));
+    assert_eq!(rete.status_schedulae.epocha, optima.epocha + 1);
+    assert_eq!(rete.status_schedulae.celeritas, Some(optima.celeritas));
+}
+
+/// Probat spatium laboris formae veteris (unum rete cum pesi_ih, bias_h, pesi_ho, bias_o) adhuc restitui.
+/// Teste qu’un espace de travail de l’ancien format (un réseau avec pesi_ih, bias_h, pesi_ho, bias_o) se restaure encore.
+#[test]
+fn probatio_archivum_vetus() {
+    use super::activatio::Activatio;
+    use super::magister::MagisterNervorum;
+    use super::neuronatus::Neuronatus;
+    use super::perditio::Perditio;
+    use super::stratum::StratumDensum;
+
+    let via = std::env::temp_dir().join("probatio_archivum_vetus.json");
+    std::fs::write(
+        &via,
+        r#"{"retia":{"xor":{"input":2,"hidden":2,"output":1,
+            "pesi_ih":{"materia":[[0.5,-0.5],[1.0,0.25]],"ordines":2,"columnae":2},
+            "pesi_ho":{"materia":[[1.0,-1.0]],"ordines":1,"columnae":2},
+            "bias_h":{"materia":[0.1,-0.1],"magnitudo":2},
+            "bias_o":{"materia":[0.2],"magnitudo":1},
+            "celeritas":0.3}}}"#,
+    )
+    .unwrap();
+
+    let magister = MagisterNervorum::restitue_ex(via.to_str().unwrap()).unwrap();
+    let rete = &magister.retia["xor"];
+    let stratum = |pesi: Vec<Vec<f64>>, bias: Vec<f64>| StratumDensum {
+        pesi: Tensor2D::ex_vec(pesi),
+        bias: Tensor1D::ex_vec(bias),
+        activatio: Activatio::Sigmoides,
+        omissio: 0.0,
+        normalizatio: None,
+    };
+    let exspectatum = Neuronatus::ex_stratis(
+        vec![stratum(vec![vec![0.5, -0.5], vec![1.0, 0.25]], vec![0.1, -0.1]), stratum(vec![vec![1.0, -1.0]], vec![0.2])],
+        Perditio::ErrorQuadraticus,
+        0.3,
+    );
+
+    assert_eq!(rete.strata.len(), 2);
+    assert_eq!(rete.celeritas, 0.3);
+    let intrata = Tensor1D::ex_vec(vec![1.0, 0.0]);
+    assert_eq!(rete.praedictio(&intrata), exspectatum.praedictio(&intrata));
+
+    // Rete migratum in forma nova salvatur.
+    // Le réseau migré est sauvegardé au nouveau format.
+    rete.salva_in(via.to_str().unwrap()).unwrap();
+    let restitutum = Neuronatus::restitue_ex(via.to_str().unwrap()).unwrap();
+    assert_eq!(restitutum.praedictio(&intrata), exspectatum.praedictio(&intrata));
+    std::fs::remove_file(via).unwrap();
+}
+
+}
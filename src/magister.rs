@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use crate::neuronatus::Neuronatus;
+use crate::neuronatus::{migra_formam_veterem, Neuronatus};
 use minitensor::Tensor1D;
 use serde::{Serialize, Deserialize};
 use std::fs::File;
@@ -7,11 +7,11 @@ use std::io::{Write, BufReader};
 
 /// Magister Nervorum.
 /// Maître des neurones : gère plusieurs réseaux de neurones.
+#[derive(Serialize, Deserialize)]
 pub struct MagisterNervorum {
     pub retia: HashMap<String, Neuronatus>,
 }
 
-#[derive(Serialize, Deserialize)]
 impl MagisterNervorum {
     /// Crea novum magistrum sine rete.
 /// Crée un gestionnaire vide.
@@ -59,12 +59,16 @@ impl MagisterNervorum {
         Ok(())
     }
 
-    /// Restitue magistrum e archivo.
-/// Restaure un gestionnaire entier depuis un fichier.
+    /// Restitue magistrum e archivo; retia formae veteris migrantur.
+/// Restaure un gestionnaire entier depuis un fichier ; les réseaux de l’ancien format sont migrés.
     pub fn restitue_ex(via: &str) -> std::io::Result<Self> {
         let file = File::open(via)?;
         let reader = BufReader::new(file);
-        let magister: MagisterNervorum = serde_json::from_reader(reader)?;
+        let mut valor: serde_json::Value = serde_json::from_reader(reader)?;
+        if let Some(retia) = valor.get_mut("retia").and_then(|retia| retia.as_object_mut()) {
+            retia.values_mut().for_each(migra_formam_veterem);
+        }
+        let magister: MagisterNervorum = serde_json::from_value(valor)?;
         Ok(magister)
     }
 }
@@ -1,189 +1,887 @@
-use minitensor::{Tensor1D, Tensor2D, Tensor3D};
-use crate::activatio::*;
-use serde::{Serialize, Deserialize};
-
-/// Structura cerebri artificialis simplex.
-/// Structure d’un cerveau artificiel simple (réseau de neurones MLP).
+use minitensor::{Numerus, TensorT1D, TensorT2D, TensorT3D};
+use crate::activatio::Activatio;
+use crate::initialisatio::Initialisatio;
+use crate::gradientes::{Gradientes, GradientesStrati, Tonsura};
+use crate::normalizatio::{MemoriaNormalizationis, Normalizatio};
+use crate::optimizator::{Optimizator, StatusOptimizatoris, StatusStrati};
+use crate::perditio::Perditio;
+use crate::regularizatio::Regularizatio;
+use crate::schedula::{Schedula, StatusSchedulae};
+use crate::validatio::{divide_exempla, Cessatio, HistoriaDisciplinae, MensuraEpochae, Validatio};
+use crate::stratum::{StratumDensum, columna, ex_columnis, per_columnas, per_columnas_bina, verifica_omissionem};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as _;
+use std::fs::File;
+use std::io::{Write, BufReader};
+use std::marker::PhantomData;
+
+/// Structura cerebri artificialis: series stratorum densorum.
+/// Structure d’un cerveau artificiel : perceptron multicouche de profondeur arbitraire.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Neuronatus {
-    pub input: usize,
-    pub hidden: usize,
-    pub output: usize,
+#[serde(bound = "")]
+pub struct NeuronatusT<T: Numerus> {
+    pub strata: Vec<StratumDensum<T>>,  // Couches, de l’entrée vers la sortie
 
-    pub pesi_ih: Tensor2D,     // Poids entrée → cachée
-    pub pesi_ho: Tensor2D,     // Poids cachée → sortie
-    pub bias_h: Tensor1D,      // Biais couche cachée
-    pub bias_o: Tensor1D,      // Biais couche de sortie
+    #[serde(default)]
+    pub perditio: Perditio,    // Fonction de perte (entraînement et évaluation)
 
     pub celeritas: f64,        // Taux d’apprentissage
+
+    #[serde(default)]
+    pub optimizator: Optimizator,   // Règle de mise à jour des poids
+
+    #[serde(default)]
+    pub status_optimizatoris: StatusOptimizatoris<T>,  // Moments de l’optimiseur, pour reprendre l’entraînement
+
+    #[serde(default = "magnitudo_batch_praefinita")]
+    pub magnitudo_batch: usize,  // Exemples par mise à jour dans instruere_batch
+
+    #[serde(default)]
+    pub tonsura: Tonsura,  // Écrêtage des gradients avant chaque mise à jour
+
+    #[serde(default)]
+    pub regularizatio: Regularizatio,  // Pénalités L1/L2 sur les poids, dans la perte et le gradient
+
+    #[serde(skip)]
+    pub norma_gradientis: Option<f64>,  // Norme L2 du dernier gradient, avant écrêtage
+
+    #[serde(default)]
+    pub schedula: Schedula,  // Planification de celeritas au fil des époques de disciplina
+
+    #[serde(default)]
+    pub status_schedulae: StatusSchedulae,  // Époque et taux courants, pour reprendre l’entraînement
+
+    #[serde(default)]
+    pub cessatio: Cessatio,  // Arrêt anticipé et restauration des meilleurs poids dans disciplina_validata
+
+    #[serde(skip)]
+    pub modus: Modus,  // Entraînement (dropout actif dans instruere) ou inférence
+
+    // Generator omissionis; si nullus, generator fili adhibetur (non reproducibilis).
+    // Générateur du dropout ; s’il est absent, le générateur du thread est utilisé (non reproductible).
+    #[serde(skip)]
+    aleator: Option<ChaCha8Rng>,
+
+    // Typus elementorum ("f32" vel "f64") in archivo scribitur et in restitutione verificatur.
+    // Le type d’élément est écrit dans le fichier et vérifié à la restauration.
+    #[serde(default, serialize_with = "scribe_praecisionem", deserialize_with = "lege_praecisionem")]
+    praecisio: PhantomData<T>,
+}
+
+/// Neuronatus ponderum f64, typus praefinitus; `NeuronatusT::<f32>` pro f32.
+/// Réseau à poids f64, le type par défaut ; `NeuronatusT::<f32>` pour f32.
+pub type Neuronatus = NeuronatusT<f64>;
+
+/// Modus retis: disciplina (omissio activa) vel inferentia (omissio inactiva).
+/// Mode du réseau : entraînement (dropout actif) ou inférence (dropout inactif).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Modus {
+    #[default]
+    Disciplina,
+    Inferentia,
+}
+
+/// Resultata propagationis antea, retropropagationi necessaria.
+/// Résultats de la propagation avant, nécessaires à la rétropropagation.
+struct Propagatio<T> {
+    summae: Vec<TensorT2D<T>>,          // z (normalisée s’il y a lieu) de chaque couche [sortie x lot]
+    activationes: Vec<TensorT2D<T>>,    // Entrée puis sortie (après dropout) de chaque couche
+    larvae: Vec<Option<TensorT2D<T>>>,  // Masques m / (1 − p) du dropout, par couche
+    memoriae: Vec<Option<MemoriaNormalizationis<T>>>,  // Mémoire de la normalisation, par couche
+}
+
+/// Instantanea epochae optimae: strata, status optimizatoris et status schedulae.
+/// Instantané de la meilleure époque : couches, état de l’optimiseur et état de la planification.
+type Instantanea<T> = (Vec<StratumDensum<T>>, StatusOptimizatoris<T>, StatusSchedulae);
+
+/// Statisticae minibatch normalizationis per batch ex `gradientes_minibatch_cum`, per `renova_statisticas` applicandae.
+/// Statistiques du mini-lot de la normalisation par lot issues de `gradientes_minibatch_cum`, à appliquer via `renova_statisticas`.
+#[derive(Debug, Clone)]
+pub struct StatisticaeBatch<T> {
+    memoriae: Vec<Option<MemoriaNormalizationis<T>>>,  // Mémoire de la normalisation, par couche
 }
 
-impl Neuronatus {
-    /// Crea novum neuronatum.
-/// Crée un nouveau réseau de neurones.
+impl<T: Numerus> NeuronatusT<T> {
+    /// Crea novum neuronatum cum uno strato occulto.
+/// Crée un nouveau réseau de neurones à une seule couche cachée.
     pub fn novus(input: usize, hidden: usize, output: usize, celeritas: f64) -> Self {
+        Self::ex_magnitudinibus(&[input, hidden, output], celeritas)
+    }
+
+    /// Crea neuronatum ex magnitudinibus stratorum: [input, occulta..., output], omnia cum sigmoide
+    /// (pondera Xavier, biases nulli).
+/// Crée un réseau à partir des tailles de couches : [entrée, cachées..., sortie], toutes sigmoïdes
+/// (poids Xavier, biais nuls).
+    pub fn ex_magnitudinibus(magnitudines: &[usize], celeritas: f64) -> Self {
+        Self::ex_magnitudinibus_cum(magnitudines, celeritas, &mut rand::rng())
+    }
+
+    /// Sicut `ex_magnitudinibus`, sed pondera ex generatore dato trahuntur.
+/// Comme `ex_magnitudinibus`, mais les poids sont tirés du générateur donné.
+    pub fn ex_magnitudinibus_cum<R: Rng + ?Sized>(magnitudines: &[usize], celeritas: f64, aleator: &mut R) -> Self {
+        let pesi = Initialisatio::pro_activatione(Activatio::Sigmoides);
+        Self::ex_magnitudinibus_initialisatum_cum(magnitudines, celeritas, pesi, Initialisatio::Nullus, aleator)
+    }
+
+    /// Sicut `ex_magnitudinibus_cum`, sed cum rationibus initialisationis datis pro ponderibus et biasibus.
+/// Comme `ex_magnitudinibus_cum`, mais avec les schémas d’initialisation donnés pour les poids et les biais.
+    pub fn ex_magnitudinibus_initialisatum_cum<R: Rng + ?Sized>(
+        magnitudines: &[usize],
+        celeritas: f64,
+        pesi: Initialisatio,
+        bias: Initialisatio,
+        aleator: &mut R,
+    ) -> Self {
+        assert!(magnitudines.len() >= 2, "Saltem input et output necessaria sunt.");
+
+        let strata = magnitudines
+            .windows(2)
+            .map(|par| StratumDensum::initialisatus(par[0], par[1], Activatio::Sigmoides, pesi, bias, aleator))
+            .collect();
+
+        Self::ex_stratis(strata, Perditio::ErrorQuadraticus, celeritas)
+    }
+
+    /// Sicut `ex_magnitudinibus`, sed idem semen eadem pondera initialia (bit pro bit) reddit.
+/// Comme `ex_magnitudinibus`, mais une même graine donne les mêmes poids initiaux, bit à bit.
+    pub fn ex_magnitudinibus_ex_semine(magnitudines: &[usize], celeritas: f64, semen: u64) -> Self {
+        let mut rete = Self::ex_magnitudinibus_cum(magnitudines, celeritas, &mut ChaCha8Rng::seed_from_u64(semen));
+        rete.pone_semen(semen);
+        rete
+    }
+
+    /// Pone semen generatoris omissionis, ut disciplina cum omissione reproducibilis sit. Larvae ex
+    /// fluxu proprio eiusdem seminis trahuntur, a ponderibus initialibus independenti.
+/// Fixe la graine du générateur de dropout, pour un entraînement reproductible. Les masques sont tirés
+/// d’un flux propre de la même graine, indépendant des poids initiaux.
+    pub fn pone_semen(&mut self, semen: u64) {
+        let mut aleator = ChaCha8Rng::seed_from_u64(semen);
+        aleator.set_stream(FLUXUS_OMISSIONIS);
+        self.aleator = Some(aleator);
+    }
+
+    /// Pone probabilitatem omissionis `p` in omnibus stratis occultis (ultimo excluso); `p` in [0, 1) esse debet.
+/// Fixe la probabilité de dropout `p` sur toutes les couches cachées (la dernière exclue) ; `p` doit être dans [0, 1).
+    pub fn pone_omissionem(&mut self, p: f64) {
+        verifica_omissionem(p);
+        let ultimus = self.strata.len() - 1;
+        for stratum in &mut self.strata[..ultimus] {
+            stratum.omissio = p;
+        }
+    }
+
+    /// Adde normalizationem in omnibus stratis occultis, e.g. `rete.pone_normalizationem(Normalizatio::batch)`.
+/// Ajoute une normalisation à toutes les couches cachées, p. ex. `rete.pone_normalizationem(Normalizatio::batch)`.
+    /// Normalizatio per batch statisticas minibatch requirit: `magnitudo_batch` < 2 ad `MAGNITUDO_BATCH_NORMALIZATIONIS` augetur.
+/// La normalisation par lot exige des statistiques de mini-lot : `magnitudo_batch` < 2 passe à `MAGNITUDO_BATCH_NORMALIZATIONIS`.
+    pub fn pone_normalizationem(&mut self, normalizatio: impl Fn(usize) -> Normalizatio<T>) {
+        let ultimus = self.strata.len() - 1;
+        for stratum in &mut self.strata[..ultimus] {
+            stratum.normalizatio = Some(normalizatio(stratum.exitus()));
+        }
+        if self.normalizatio_per_batch() && self.magnitudo_batch < 2 {
+            self.magnitudo_batch = MAGNITUDO_BATCH_NORMALIZATIONIS;
+        }
+    }
+
+    /// Verum si aliquod stratum normalizationem per batch habet.
+/// Vrai si une couche au moins a une normalisation par lot.
+    pub fn normalizatio_per_batch(&self) -> bool {
+        self.strata.iter().any(|stratum| matches!(stratum.normalizatio, Some(Normalizatio::Batch { .. })))
+    }
+
+    /// Crea neuronatum ex stratis iam paratis et functione perditionis.
+/// Crée un réseau à partir de couches déjà construites et d’une fonction de perte.
+    pub fn ex_stratis(strata: Vec<StratumDensum<T>>, perditio: Perditio, celeritas: f64) -> Self {
+        assert!(!strata.is_empty(), "Saltem unum stratum esse debet.");
+
+        // Exitus cuiusque strati inputum sequentis esse debet.
+        // La sortie de chaque couche doit correspondre à l’entrée de la suivante.
+        for par in strata.windows(2) {
+            assert_eq!(par[0].exitus(), par[1].introitus(), "Strata contigua non congruunt.");
+        }
+
         Self {
-            input,
-            hidden,
-            output,
-            pesi_ih: Tensor2D::fortuitus(hidden, input),
-            pesi_ho: Tensor2D::fortuitus(output, hidden),
-            bias_h: Tensor1D::fortuitus(hidden),
-            bias_o: Tensor1D::fortuitus(output),
+            strata,
+            perditio,
             celeritas,
+            optimizator: Optimizator::Sgd,
+            status_optimizatoris: StatusOptimizatoris::default(),
+            magnitudo_batch: magnitudo_batch_praefinita(),
+            tonsura: Tonsura::default(),
+            regularizatio: Regularizatio::default(),
+            norma_gradientis: None,
+            schedula: Schedula::default(),
+            status_schedulae: StatusSchedulae::default(),
+            cessatio: Cessatio::default(),
+            modus: Modus::default(),
+            aleator: None,
+            praecisio: PhantomData,
         }
     }
 
+    /// Converte rete (pondera et statum optimizatoris) in aliam praecisionem, e.g. f64 → f32.
+/// Convertit le réseau (poids et état de l’optimiseur) vers une autre précision, p. ex. f64 → f32.
+    pub fn converte<U: Numerus>(&self) -> NeuronatusT<U> {
+        NeuronatusT {
+            strata: self.strata.iter().map(|stratum| stratum.converte()).collect(),
+            perditio: self.perditio,
+            celeritas: self.celeritas,
+            optimizator: self.optimizator,
+            status_optimizatoris: self.status_optimizatoris.converte(),
+            magnitudo_batch: self.magnitudo_batch,
+            tonsura: self.tonsura,
+            regularizatio: self.regularizatio,
+            norma_gradientis: self.norma_gradientis,
+            schedula: self.schedula,
+            status_schedulae: self.status_schedulae.clone(),
+            cessatio: self.cessatio,
+            modus: self.modus,
+            aleator: self.aleator.clone(),
+            praecisio: PhantomData,
+        }
+    }
+
+    /// Nomen typi elementorum retis ("f32" vel "f64").
+/// Nom du type d’élément du réseau ("f32" ou "f64").
+    pub fn praecisio(&self) -> &'static str {
+        T::NOMEN
+    }
+
+    /// Pone novum optimizatorem; status prior (momenta) deletur.
+/// Définit un nouvel optimiseur ; l’état précédent (moments) est effacé.
+    pub fn pone_optimizatorem(&mut self, optimizator: Optimizator) {
+        self.optimizator = optimizator;
+        self.status_optimizatoris = StatusOptimizatoris::default();
+    }
+
+    /// Magnitudo inputi retis.
+/// Taille de l’entrée du réseau.
+    pub fn input(&self) -> usize {
+        self.strata[0].introitus()
+    }
+
+    /// Magnitudo exitus retis.
+/// Taille de la sortie du réseau.
+    pub fn output(&self) -> usize {
+        self.strata[self.strata.len() - 1].exitus()
+    }
+
+    /// Propagatio antea per omnia strata, una pro praedictione et disciplina. `disciplina` statisticas
+    /// minibatch normalizationi per batch dat; cum generatore omissio in stratis occultis applicatur.
+/// Propagation avant commune à la prédiction et à l’entraînement. `disciplina` donne les statistiques
+/// du mini-lot à la normalisation par lot ; avec un générateur, le dropout des couches cachées est appliqué.
+    fn propaga(&self, intratae: &TensorT2D<T>, disciplina: bool, mut aleator: Option<&mut dyn RngCore>) -> Propagatio<T> {
+        let ultimus = self.strata.len() - 1;
+        let mut propagatio = Propagatio {
+            summae: Vec::with_capacity(self.strata.len()),
+            activationes: vec![intratae.clone()], // [n x batch]
+            larvae: Vec::with_capacity(self.strata.len()),
+            memoriae: Vec::with_capacity(self.strata.len()),
+        };
+
+        for (index, stratum) in self.strata.iter().enumerate() {
+            let (summa, memoria) = stratum.summa_normata(&propagatio.activationes[index], disciplina);
+            let mut activatio = per_columnas(&summa, |z| stratum.activatio.applica(z));
+
+            // Omissio inversa: a ⊙ m / (1 − p), ut exspectatio activationis non mutetur.
+            // Dropout inversé : a ⊙ m / (1 − p), pour que l’espérance de l’activation reste inchangée.
+            let larva = match aleator.as_deref_mut() {
+                Some(aleator) if index < ultimus && stratum.omissio > 0.0 => {
+                    let larva = larva_omissionis(activatio.ordines, activatio.columnae, stratum.omissio, aleator);
+                    activatio = activatio.zip_map(&larva, |a, m| a * m);
+                    Some(larva)
+                }
+                _ => None,
+            };
+
+            propagatio.summae.push(summa);
+            propagatio.activationes.push(activatio);
+            propagatio.larvae.push(larva);
+            propagatio.memoriae.push(memoria);
+        }
+
+        propagatio
+    }
+
     /// Praedictio: dat vectorem inputum, reddit vectorem exitus.
 /// Propagation avant : donne une prédiction à partir d’un vecteur d’entrée.
-    pub fn praedictio(&self, intrata: &Tensor1D) -> Tensor1D {
-        use minitensor::Tensor1D;
+    pub fn praedictio(&self, intrata: &TensorT1D<T>) -> TensorT1D<T> {
+        self.praedictio_minibatch(&intrata.transpone()).columna(0)
+    }
+
+    /// Praedictio pro toto minibatch: columnae inputi sunt exempla.
+/// Prédiction pour un mini-lot entier : chaque colonne est un exemple.
+    pub fn praedictio_minibatch(&self, intratae: &TensorT2D<T>) -> TensorT2D<T> {
+        // Praedictio semper in modo inferentiae: sine omissione, cum statisticis currentibus.
+        // La prédiction est toujours en mode inférence : sans dropout, avec les statistiques courantes.
+        let mut propagatio = self.propaga(intratae, false, None);
+        propagatio.activationes.pop().unwrap()
+    }
+
+    /// Instruere rete: unam iterationem discentis perfice.
+/// Entraîne le réseau : effectue une itération d’apprentissage.
+    pub fn instruere(&mut self, intrata: &TensorT1D<T>, exspectata: &TensorT1D<T>) {
+        self.instruere_minibatch(&intrata.transpone(), &exspectata.transpone());
+    }
+
+    /// Instruere rete per minibatch: gradientes per omnia exempla mediantur, deinde una renovatio fit.
+/// Entraîne sur un mini-lot : les gradients sont moyennés sur les exemples avant une seule mise à jour.
+    pub fn instruere_minibatch(&mut self, intratae: &TensorT2D<T>, exspectatae: &TensorT2D<T>) {
+        // Larvae ex generatore retis (si semen datum est), alioquin ex generatore fili trahuntur;
+        // statisticae currentes normalizationis hic solum renovantur.
+        // Les masques viennent du générateur du réseau (si une graine est fixée), sinon de celui du thread ;
+        // les statistiques courantes de la normalisation ne sont mises à jour qu’ici.
+        let mut aleator = self.aleator.take();
+        let (mut gradientes, statisticae) = match aleator.as_mut() {
+            Some(aleator) => self.gradientes_minibatch_cum(intratae, exspectatae, Some(aleator)),
+            None => self.gradientes_minibatch_cum(intratae, exspectatae, Some(&mut rand::rng())),
+        };
+        self.aleator = aleator;
+        self.renova_statisticas(&statisticae);
+
+        // Tonsura inter computationem et renovationem; norma ante tonsuram servatur.
+        // Écrêtage entre le calcul et la mise à jour ; la norme avant écrêtage est conservée.
+        self.norma_gradientis = Some(gradientes.tonde(&self.tonsura).in_f64());
+        self.applica(&gradientes);
+    }
+
+    /// Gradientes perditionis unius exempli respectu omnium parametrorum; pondera non mutantur.
+/// Gradients de la perte d’un exemple par rapport à tous les paramètres ; les poids ne sont pas modifiés.
+    pub fn gradientes(&self, intrata: &TensorT1D<T>, exspectata: &TensorT1D<T>) -> Gradientes<T> {
+        self.gradientes_minibatch(&intrata.transpone(), &exspectata.transpone())
+    }
+
+    /// Retropropagatio: gradientes perditionis mediae minibatch respectu ponderum et biasis cuiusque strati.
+    /// Sine omissione; in modo disciplinae normalizatio per batch statisticas minibatch adhibet, sed
+    /// statisticae currentes non renovantur.
+/// Rétropropagation : gradients de la perte moyenne du mini-lot par rapport aux poids et biais de chaque couche.
+/// Sans dropout ; en mode entraînement la normalisation par lot utilise les statistiques du mini-lot,
+/// mais les statistiques courantes ne sont pas mises à jour.
+    pub fn gradientes_minibatch(&self, intratae: &TensorT2D<T>, exspectatae: &TensorT2D<T>) -> Gradientes<T> {
+        self.gradientes_minibatch_cum(intratae, exspectatae, None).0
+    }
 
-        // Convertit inputum in columnam
-        let intrata_col = intrata.transpone(); // [n x 1]
+    /// Retropropagatio cum generatore omissionis explicito: in modo disciplinae larvae ex `aleator`
+    /// (si datur) trahuntur. Reddit etiam statisticas minibatch, quas `renova_statisticas` applicat.
+/// Rétropropagation avec un générateur de dropout explicite : en mode entraînement, les masques sont tirés
+/// de `aleator` (s’il est fourni). Renvoie aussi les statistiques du mini-lot, appliquées par `renova_statisticas`.
+    pub fn gradientes_minibatch_cum(
+        &self,
+        intratae: &TensorT2D<T>,
+        exspectatae: &TensorT2D<T>,
+        aleator: Option<&mut dyn RngCore>,
+    ) -> (Gradientes<T>, StatisticaeBatch<T>) {
+        assert_eq!(intratae.columnae, exspectatae.columnae, "Batch amplitudo debet convenire.");
+        let magnitudo = T::ex_f64(intratae.columnae as f64);
+
+        // 1. Propagatio antea: summae z (ante activationem), activationes a, larvae omissionis et memoriae
+        //    normalizationis servantur; in modo inferentiae nec omissio nec statisticae minibatch
+        let Propagatio { summae, activationes, larvae, memoriae } = match self.modus {
+            Modus::Inferentia => self.propaga(intratae, false, None),
+            Modus::Disciplina => self.propaga(intratae, true, aleator),
+        };
+
+        // 2. Calcula gradientem outputi: ∂L/∂y, vel ∂L/∂z si perditio cum activatione fusa est,
+        //    per magnitudinem minibatch divisum ut gradientes mediae fiant
+        let ultimus = self.strata.len() - 1;
+        let perditio = self.perditio;
+        let fusa = perditio.fusa_cum(&self.strata[ultimus].activatio);
+        let mut error = per_columnas_bina(&activationes[ultimus + 1], exspectatae, |exitus, exspectatum| {
+            if fusa {
+                perditio.gradiens_summarum(exitus, exspectatum)
+            } else {
+                perditio.gradiens(exitus, exspectatum)
+            }
+        }) / magnitudo;
+
+        // 3. Retropropagatio ab ultimo strato ad primum
+        let mut strata = Vec::with_capacity(self.strata.len());
+        for (index, stratum) in self.strata.iter().enumerate().rev() {
+            // Per larvam omissionis: ∂L/∂a = ∂L/∂ã ⊙ m / (1 − p)
+            if let Some(larva) = &larvae[index] {
+                error = error.zip_map(larva, |e, m| e * m);
+            }
+
+            // Delta δ = f'(z) ⊙ ∂L/∂a (vel Iacobiana softmax), in summis z computata [exitus x batch]
+            let delta = if fusa && index == ultimus {
+                error
+            } else {
+                per_columnas_bina(&summae[index], &error, |z, e| stratum.activatio.retro(z, e))
+            };
+
+            // Per normalizationem (si adest): δ in summis normatis → δ in summis z, cum gradientibus γ, β
+            let (delta, normalizatio) = match (&stratum.normalizatio, &memoriae[index]) {
+                (Some(normalizatio), Some(memoria)) => {
+                    let (delta, gradientes) = normalizatio.retro(memoria, &delta);
+                    (delta, Some(gradientes))
+                }
+                _ => (delta, None),
+            };
+
+            // Gradiens pondus: δ × activatio_priorᵗ (summa per batch), bias: summa ordinum
+            strata.push(GradientesStrati {
+                pesi: delta.productum_matriciale(&activationes[index].transpone()),
+                bias: delta.summa_ordinum(),
+                normalizatio,
+            });
+
+            // Error pro strato priore: Wᵗ·δ, cum ponderibus nondum renovatis
+            error = stratum.pesi.transpone().productum_matriciale(&delta);
+        }
 
-        // Calcula activitatem intermediam
-        let mut activatio_h = self.pesi_ih.productum_matriciale(&intrata_col); // [hidden x 1]
-        activatio_h = activatio_h.adde(&self.bias_h.transpone());
-        let activatio_h = Tensor1D::ex_vec(activatio_h.materia.iter().map(|v| sigmoide(v[0])).collect());
+        strata.reverse();
 
-        // Calcula exitum
-        let mut activatio_o = self.pesi_ho.productum_matriciale(&activatio_h.transpone());
-        activatio_o = activatio_o.adde(&self.bias_o.transpone());
-        let activatio_o = Tensor1D::ex_vec(activatio_o.materia.iter().map(|v| sigmoide(v[0])).collect());
+        // Gradiens poenae regularizationis (si activa) additur.
+        // Le gradient de la pénalité de régularisation (si active) est ajouté.
+        let mut gradientes = Gradientes { strata };
+        self.regularizatio.adde_gradientes(&self.strata, &mut gradientes);
+        (gradientes, StatisticaeBatch { memoriae })
+    }
 
-        activatio_o
+    /// Renova statisticas currentes normalizationis per batch ex statisticis minibatch.
+/// Met à jour les statistiques courantes de la normalisation par lot à partir de celles du mini-lot.
+    pub fn renova_statisticas(&mut self, statisticae: &StatisticaeBatch<T>) {
+        for (stratum, memoria) in self.strata.iter_mut().zip(&statisticae.memoriae) {
+            if let (Some(normalizatio), Some(memoria)) = (&mut stratum.normalizatio, memoria) {
+                normalizatio.renova_statisticas(memoria);
+            }
+        }
     }
-    
-        /// Instruere rete: unam iterationem discentis perfice.
-/// Entraîne le réseau : effectue une itération d’apprentissage.
-    pub fn instruere(&mut self, intrata: &Tensor1D, exspectata: &Tensor1D) {
-        // 1. Propagatio antea
-        let intrata_col = intrata.transpone();
-
-        let mut summa_h = self.pesi_ih.productum_matriciale(&intrata_col);
-        summa_h = summa_h.adde(&self.bias_h.transpone());
-        let activatio_h_vec: Vec<f64> = summa_h.materia.iter().map(|v| sigmoide(v[0])).collect();
-        let activatio_h = Tensor1D::ex_vec(activatio_h_vec.clone());
-
-        let mut summa_o = self.pesi_ho.productum_matriciale(&activatio_h.transpone());
-        summa_o = summa_o.adde(&self.bias_o.transpone());
-        let activatio_o_vec: Vec<f64> = summa_o.materia.iter().map(|v| sigmoide(v[0])).collect();
-        let activatio_o = Tensor1D::ex_vec(activatio_o_vec.clone());
-
-        // 2. Calcula errorem outputi
-        let error_o = exspectata.adde(&activatio_o.scale(-1.0));
-
-        // 3. Derivata sigmoidis × error → gradient
-        let derivata_o: Vec<f64> = activatio_o_vec.iter().map(|&x| derivata_sigmoidis(x)).collect();
-        let gradient_o = Tensor1D::ex_vec(
-            derivata_o.iter()
-                      .zip(error_o.materia.iter())
-                      .map(|(ds, err)| ds * err * self.celeritas)
-                      .collect(),
-        );
 
-        // 4. Deltas pondus: gradient_o × activatio_hᵗ
-        let delta_ho = gradient_o.transpone().productum_matriciale(&activatio_h.transpone().transpone());
-
-        // 5. Update pondus et bias output
-        self.pesi_ho = self.pesi_ho.adde(&delta_ho);
-        self.bias_o = self.bias_o.adde(&gradient_o);
-
-        // 6. Errorem pro strato occulto
-        let pesi_hoᵗ = self.pesi_ho.transpone();
-        let error_h = pesi_hoᵗ.productum_matriciale(&error_o.transpone());
-
-        // 7. Derivata sigmoidis × error_h
-        let derivata_h: Vec<f64> = activatio_h_vec.iter().map(|&x| derivata_sigmoidis(x)).collect();
-        let error_h_vec = error_h.materia.iter().map(|v| v[0]).collect::<Vec<f64>>();
-        let gradient_h = Tensor1D::ex_vec(
-            derivata_h.iter()
-                      .zip(error_h_vec.iter())
-                      .map(|(ds, err)| ds * err * self.celeritas)
-                      .collect(),
-        );
+    /// Applica gradientes per optimizatorem retis, statum eius renovans.
+/// Applique les gradients via l’optimiseur du réseau, en mettant à jour son état.
+    pub fn applica(&mut self, gradientes: &Gradientes<T>) {
+        let (optimizator, celeritas) = (self.optimizator, self.celeritas_currens());
+        renova_strata(&mut self.strata, gradientes, optimizator, &mut self.status_optimizatoris, celeritas);
+    }
 
-        // 8. Update pondus input → hidden
-        let delta_ih = gradient_h.transpone().productum_matriciale(&intrata_col.transpone());
-        self.pesi_ih = self.pesi_ih.adde(&delta_ih);
-        self.bias_h = self.bias_h.adde(&gradient_h);
+    /// Applica gradientes per optimizatorem et statum externum (status retis non mutatur).
+/// Applique les gradients avec un optimiseur et un état externes (l’état du réseau n’est pas modifié).
+    pub fn applica_cum(
+        &mut self,
+        gradientes: &Gradientes<T>,
+        optimizator: Optimizator,
+        status: &mut StatusOptimizatoris<T>,
+        celeritas: f64,
+    ) {
+        renova_strata(&mut self.strata, gradientes, optimizator, status, celeritas);
     }
-    
-    /// Instruere rete per batch.
-/// Entraîne le réseau sur un batch d'exemples.
-pub fn instruere_batch(&mut self, intratae: &Tensor3D, exspectatae: &Tensor3D) {
-    // Verifica dimensiones
-    assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
 
-    for index in 0..intratae.profunditas {
-        let input_slice = &intratae.materia[index][0]; // Tensor1D attendu sous forme [1][input]
-        let target_slice = &exspectatae.materia[index][0];
+    /// Instruere rete per batch: exempla in minibatches `magnitudo_batch` dividuntur.
+/// Entraîne le réseau sur un batch, découpé en mini-lots de `magnitudo_batch` exemples.
+    pub fn instruere_batch(&mut self, intratae: &TensorT3D<T>, exspectatae: &TensorT3D<T>) {
+        // Verifica dimensiones
+        assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+        assert!(
+            !self.normalizatio_per_batch() || self.magnitudo_batch >= 2,
+            "Normalizatio per batch magnitudinem batch saltem 2 requirit."
+        );
+        let magnitudo = self.magnitudo_batch.max(1);
 
-        let inputum = Tensor1D::ex_vec(input_slice.clone());
-        let exspectatum = Tensor1D::ex_vec(target_slice.clone());
+        let mut initium = 0;
+        while initium < intratae.profunditas {
+            let finis = (initium + magnitudo).min(intratae.profunditas);
+            let inputa = columnae_exemplorum(intratae, initium, finis);
+            let exspectata = columnae_exemplorum(exspectatae, initium, finis);
 
-        self.instruere(&inputum, &exspectatum);
+            self.instruere_minibatch(&inputa, &exspectata);
+            initium = finis;
+        }
     }
-}
 
-use std::fmt;
+    /// Celeritas currens: a schedula activa in disciplina posita, alioquin `celeritas` ipsa
+    /// (sub `Schedula::Constans` celeritas servata neglegitur, ut mutationes `celeritas` valeant).
+/// Taux courant : fixé par une planification active pendant disciplina, sinon `celeritas` elle-même
+/// (avec `Schedula::Constans` le taux sauvegardé est ignoré, afin que les changements de `celeritas` comptent).
+    pub fn celeritas_currens(&self) -> f64 {
+        match self.schedula {
+            Schedula::Constans => self.celeritas,
+            _ => self.status_schedulae.celeritas.unwrap_or(self.celeritas),
+        }
+    }
 
-/// Disciplina multi-epochalis.
+    /// Disciplina multi-epochalis.
 /// Effectue plusieurs itérations d’apprentissage.
-pub fn disciplina(
-    &mut self,
-    intratae: &Tensor3D,
-    exspectatae: &Tensor3D,
-    epochs: usize,
-    silentium: bool, // true = pas d'affichage
-) {
-    assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+    pub fn disciplina(
+        &mut self,
+        intratae: &TensorT3D<T>,
+        exspectatae: &TensorT3D<T>,
+        epochs: usize,
+        silentium: bool, // true = pas d'affichage
+    ) {
+        self.disce_epochas(intratae, exspectatae, None, epochs, silentium);
+    }
+
+    /// Disciplina cum validatione: post omnem epocham perditio et accuratio validationis computantur;
+    /// secundum `self.cessatio` disciplina praecox sistitur et pondera epochae optimae restituuntur.
+/// Entraînement avec validation : après chaque époque, la perte et l’exactitude de validation sont calculées ;
+/// selon `self.cessatio`, l’entraînement s’arrête tôt et les poids de la meilleure époque sont restaurés.
+    pub fn disciplina_validata(
+        &mut self,
+        intratae: &TensorT3D<T>,
+        exspectatae: &TensorT3D<T>,
+        validatio: Validatio<'_, T>,
+        epochs: usize,
+        silentium: bool,
+    ) -> HistoriaDisciplinae {
+        match validatio {
+            Validatio::Exempla { intratae: intratae_v, exspectatae: exspectatae_v } => {
+                self.disce_epochas(intratae, exspectatae, Some((intratae_v, exspectatae_v)), epochs, silentium)
+            }
+            Validatio::Fractio(fractio) => {
+                // Permutatio ex generatore retis (si semen datum est), alioquin ex generatore fili.
+                // Permutation tirée du générateur du réseau (si une graine est fixée), sinon de celui du thread.
+                let ((intratae, exspectatae), (intratae_v, exspectatae_v)) = match self.aleator.as_mut() {
+                    Some(aleator) => divide_exempla(intratae, exspectatae, fractio, aleator),
+                    None => divide_exempla(intratae, exspectatae, fractio, &mut rand::rng()),
+                };
+                self.disce_epochas(&intratae, &exspectatae, Some((&intratae_v, &exspectatae_v)), epochs, silentium)
+            }
+        }
+    }
+
+    /// Cursus epocharum communis `disciplina` et `disciplina_validata`.
+/// Boucle d’époques commune à `disciplina` et `disciplina_validata`.
+    fn disce_epochas(
+        &mut self,
+        intratae: &TensorT3D<T>,
+        exspectatae: &TensorT3D<T>,
+        validatio: Option<(&TensorT3D<T>, &TensorT3D<T>)>,
+        epochs: usize,
+        silentium: bool,
+    ) -> HistoriaDisciplinae {
+        assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+        assert!(self.tonsura.valida(), "Limes tonsurae positivus esse debet.");
+
+        let mut historia = HistoriaDisciplinae::default();
+        let mut optimum: Option<Instantanea<T>> = None;
+        let mut mora = 0;
+
+        for epochon in 0..epochs {
+            // Celeritas huius epochae ex schedula; status cum rete servatur.
+            // Taux de cette époque selon la planification ; l’état est sauvegardé avec le réseau.
+            let celeritas = self.schedula.celeritas(self.celeritas, &self.status_schedulae);
+            self.status_schedulae.celeritas = Some(celeritas);
+
+            self.instruere_batch(intratae, exspectatae);
+
+            let indicium = !silentium && (epochon % 100 == 0 || epochon == epochs - 1);
+            let perditio = if indicium || self.schedula.perditionem_requirit() || validatio.is_some() {
+                Some(self.perditio_batch(intratae, exspectatae).in_f64())
+            } else {
+                None
+            };
+            // Perditio validationis sine poena L1/L2, ut epochae (et retia) inter se comparari possint.
+            // Perte de validation sans pénalité L1/L2, pour que les époques (et réseaux) restent comparables.
+            let mensura = validatio.map(|(intratae_v, exspectatae_v)| MensuraEpochae {
+                epocha: epochon,
+                perditio: perditio.unwrap_or_default(),
+                perditio_validationis: self.perditio_datorum(intratae_v, exspectatae_v).in_f64(),
+                accuratio_validationis: self.accuratio_batch(intratae_v, exspectatae_v),
+                celeritas,
+            });
+
+            // Reductio in planitie perditionem validationis observat, si adest.
+            // La réduction sur plateau suit la perte de validation, si elle existe.
+            let observata = mensura.map(|m| m.perditio_validationis).or(perditio);
+            self.schedula.observa(&mut self.status_schedulae, observata);
+
+            // Indicium progressus si non silentium
+            if let (true, Some(perditio)) = (indicium, perditio) {
+                let norma = match (self.tonsura.activa(), self.norma_gradientis) {
+                    (true, Some(norma)) => format!(" Norma gradientis: {:.4}", norma),
+                    _ => String::new(),
+                };
+                let validationis = match mensura {
+                    Some(m) => format!(
+                        " Validatio: {:.6} Accuratio: {:.2}%",
+                        m.perditio_validationis,
+                        100.0 * m.accuratio_validationis
+                    ),
+                    None => String::new(),
+                };
+                println!(
+                    "Epochon {:>5} / {} completus est. Perditio: {:.6} Celeritas: {:.6}{}{}",
+                    epochon + 1,
+                    epochs,
+                    perditio,
+                    celeritas,
+                    norma,
+                    validationis
+                );
+            }
+
+            let Some(mensura) = mensura else {
+                continue;
+            };
+
+            // Cessatio praecox: epocha optima servatur, mora sine progressu numeratur.
+            // Arrêt anticipé : la meilleure époque est conservée, les époques sans progrès sont comptées.
+            historia.epochae.push(mensura);
+            if self.cessatio.melior(&mensura, historia.mensura_optima()) {
+                historia.optima = Some(historia.epochae.len() - 1);
+                if self.cessatio.restitue_optima {
+                    optimum = Some((self.strata.clone(), self.status_optimizatoris.clone(), self.status_schedulae.clone()));
+                }
+                mora = 0;
+            } else {
+                mora += 1;
+                if self.cessatio.patientia.is_some_and(|patientia| mora >= patientia) {
+                    historia.interrupta = true;
+                    break;
+                }
+            }
+        }
 
-    for epochon in 0..epochs {
-        self.instruere_batch(intratae, exspectatae);
+        // Status optimizatoris et schedulae cum ponderibus restituuntur, ut disciplina ab epocha optima resumatur.
+        // L’état de l’optimiseur et de la planification est restauré avec les poids, pour reprendre depuis la meilleure époque.
+        if let Some((strata, status_optimizatoris, status_schedulae)) = optimum {
+            self.strata = strata;
+            self.status_optimizatoris = status_optimizatoris;
+            self.status_schedulae = status_schedulae;
+            historia.restituta = true;
+        }
 
-        // Indicium progressus si non silentium
-        if !silentium && (epochon % 100 == 0 || epochon == epochs - 1) {
-            let perditio = self.perditio_batch(intratae, exspectatae);
+        if let (false, Some(optima)) = (silentium, historia.mensura_optima()) {
             println!(
-                "Epochon {:>5} / {} completus est. Perditio: {:.6}",
-                epochon + 1,
-                epochs,
-                perditio
+                "Epocha optima {} (validatio: {:.6}, accuratio: {:.2}%){}{}.",
+                optima.epocha + 1,
+                optima.perditio_validationis,
+                100.0 * optima.accuratio_validationis,
+                if historia.interrupta { "; disciplina praecox sistitur" } else { "" },
+                if historia.restituta { "; pondera eius restituta sunt" } else { "" }
             );
         }
 
+        historia
     }
-}
 
-/// Perditio MSE inter duas Tensor1D.
+    /// Perditio MSE inter duas Tensor1D.
 /// Erreur quadratique moyenne entre deux vecteurs.
-pub fn perditio_mse(a: &Tensor1D, b: &Tensor1D) -> f64 {
-    assert_eq!(a.magnitudo, b.magnitudo);
-    let n = a.magnitudo as f64;
-    a.materia.iter()
-        .zip(&b.materia)
-        .map(|(x, y)| (x - y).powi(2))
-        .sum::<f64>() / n
+    pub fn perditio_mse(a: &TensorT1D<T>, b: &TensorT1D<T>) -> T {
+        assert_eq!(a.magnitudo, b.magnitudo);
+        let n = T::ex_f64(a.magnitudo as f64);
+        a.materia.iter()
+            .zip(&b.materia)
+            .map(|(&x, &y)| (x - y).powi(2))
+            .sum::<T>() / n
+    }
+
+    /// Perditio unius exempli secundum functionem perditionis retis.
+/// Perte d’un exemple selon la fonction de perte du réseau.
+    pub fn perditio_exempli(&self, intrata: &TensorT1D<T>, exspectata: &TensorT1D<T>) -> T {
+        self.perditio_minibatch(&intrata.transpone(), &exspectata.transpone())
+    }
+
+    /// Perditio media per minibatch (columnae sunt exempla), in modo inferentiae.
+/// Perte moyenne sur un mini-lot (chaque colonne est un exemple), en mode inférence.
+    pub fn perditio_minibatch(&self, intratae: &TensorT2D<T>, exspectatae: &TensorT2D<T>) -> T {
+        self.perditio_propagationis(self.propaga(intratae, false, None), exspectatae)
+    }
+
+    /// Perditio media per minibatch cum statisticis minibatch (ut in disciplina) sed sine omissione;
+    /// haec est functio quam `gradientes_minibatch` in modo disciplinae derivat.
+/// Perte moyenne sur un mini-lot avec les statistiques du mini-lot (comme à l’entraînement) mais sans
+/// dropout ; c’est la fonction que `gradientes_minibatch` dérive en mode entraînement.
+    pub fn perditio_minibatch_disciplinae(&self, intratae: &TensorT2D<T>, exspectatae: &TensorT2D<T>) -> T {
+        self.perditio_propagationis(self.propaga(intratae, true, None), exspectatae)
+    }
+
+    /// Perditio media ex propagatione iam facta, poena regularizationis inclusa.
+/// Perte moyenne à partir d’une propagation déjà effectuée, pénalité de régularisation incluse.
+    fn perditio_propagationis(&self, propagatio: Propagatio<T>, exspectatae: &TensorT2D<T>) -> T {
+        // Poena regularizationis inclusa, ut perditio obiectivum totum reddat.
+        // Pénalité de régularisation incluse, pour que la perte reflète l’objectif complet.
+        self.perditio_datorum_propagationis(propagatio, exspectatae) + self.regularizatio.poena(&self.strata)
+    }
+
+    /// Perditio media datorum ex propagatione iam facta, sine poena regularizationis.
+/// Perte moyenne des données à partir d’une propagation déjà effectuée, sans pénalité de régularisation.
+    fn perditio_datorum_propagationis(&self, propagatio: Propagatio<T>, exspectatae: &TensorT2D<T>) -> T {
+        let Propagatio { mut summae, mut activationes, .. } = propagatio;
+        assert_eq!(activationes[0].columnae, exspectatae.columnae, "Batch amplitudo debet convenire.");
+
+        // Perditio fusa ex summis computatur, ne probabilitates extremae logarithmum frangant.
+        // La perte fusionnée est calculée depuis les logits, pour la stabilité numérique.
+        let fusa = self.perditio.fusa_cum(&self.strata[self.strata.len() - 1].activatio);
+        let exitus = if fusa { summae.pop().unwrap() } else { activationes.pop().unwrap() };
+
+        let totalis: T = (0..exitus.columnae)
+            .map(|j| {
+                let exspectatum = columna(exspectatae, j);
+                if fusa {
+                    self.perditio.valor_ex_summis(&columna(&exitus, j), &exspectatum)
+                } else {
+                    self.perditio.valor(&columna(&exitus, j), &exspectatum)
+                }
+            })
+            .sum();
+
+        totalis / T::ex_f64(exitus.columnae as f64)
+    }
+
+    /// Perditio media per totum batch.
+/// Perte moyenne sur un lot d’exemples.
+    pub fn perditio_batch(&self, intratae: &TensorT3D<T>, exspectatae: &TensorT3D<T>) -> T {
+        assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+        let inputa = columnae_exemplorum(intratae, 0, intratae.profunditas);
+        let exspectata = columnae_exemplorum(exspectatae, 0, exspectatae.profunditas);
+        self.perditio_minibatch(&inputa, &exspectata)
+    }
+
+    /// Perditio media datorum per totum batch, sine poena L1/L2: mensura comparabilis inter retia
+    /// diversae regularizationis (e.g. pro validatione).
+/// Perte moyenne des données sur un lot, sans pénalité L1/L2 : mesure comparable entre réseaux
+/// de régularisations différentes (p. ex. pour la validation).
+    pub fn perditio_datorum(&self, intratae: &TensorT3D<T>, exspectatae: &TensorT3D<T>) -> T {
+        assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+        let inputa = columnae_exemplorum(intratae, 0, intratae.profunditas);
+        let exspectata = columnae_exemplorum(exspectatae, 0, exspectatae.profunditas);
+        self.perditio_datorum_propagationis(self.propaga(&inputa, false, None), &exspectata)
+    }
+
+    /// Accuratio per totum batch: pro exitu singulo limen 0.5 (0 pro perditione `Cardo`, exspectatis in {-1, 1}),
+    /// alioquin argmax exitus et exspectati congruere debent.
+/// Exactitude sur un lot : seuil 0.5 pour une sortie unique (0 pour la perte `Cardo`, cibles dans {-1, 1}),
+/// sinon les argmax de la sortie et de l’attendu doivent coïncider.
+    pub fn accuratio_batch(&self, intratae: &TensorT3D<T>, exspectatae: &TensorT3D<T>) -> f64 {
+        assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+        let exitus = self.praedictio_minibatch(&columnae_exemplorum(intratae, 0, intratae.profunditas));
+        let exspectata = columnae_exemplorum(exspectatae, 0, exspectatae.profunditas);
+
+        let recta = if exitus.ordines == 1 {
+            let limen = T::ex_f64(if self.perditio == Perditio::Cardo { 0.0 } else { 0.5 });
+            (0..exitus.columnae).filter(|&j| (exitus[(0, j)] >= limen) == (exspectata[(0, j)] >= limen)).count()
+        } else {
+            let (a, b) = (exitus.transpone().argmax_ordinum(), exspectata.transpone().argmax_ordinum());
+            a.iter().zip(&b).filter(|(a, b)| a == b).count()
+        };
+        recta as f64 / exitus.columnae.max(1) as f64
+    }
+
+    /// Salva rete in archivo JSON.
+/// Sauvegarde le réseau dans un fichier JSON.
+    pub fn salva_in(&self, via: &str) -> std::io::Result<()> {
+        let mut file = File::create(via)?;
+        let json = serde_json::to_string_pretty(self)?;
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+
+    /// Restitue rete e archivo JSON; archiva formae veteris (`pesi_ih`, `pesi_ho`) migrantur.
+/// Restaure un réseau depuis un fichier JSON ; les fichiers de l’ancien format (`pesi_ih`, `pesi_ho`) sont migrés.
+    pub fn restitue_ex(via: &str) -> std::io::Result<Self> {
+        let file = File::open(via)?;
+        let reader = BufReader::new(file);
+        let mut valor: serde_json::Value = serde_json::from_reader(reader)?;
+        migra_formam_veterem(&mut valor);
+        let rete: NeuronatusT<T> = serde_json::from_value(valor)?;
+        Ok(rete)
+    }
+}
+
+/// Fluxus generatoris ChaCha pro larvis omissionis (fluxus 0 pondera initialia trahit).
+/// Flux du générateur ChaCha réservé aux masques de dropout (le flux 0 tire les poids initiaux).
+const FLUXUS_OMISSIONIS: u64 = 1;
+
+/// Magnitudo minibatch praefinita: unum exemplum per renovationem, ut antea.
+/// Taille de mini-lot par défaut : un exemple par mise à jour, comme auparavant.
+fn magnitudo_batch_praefinita() -> usize {
+    1
 }
 
-/// Perditio MSE per totum batch.
-/// MSE sur un lot d’exemples.
-pub fn perditio_batch(&self, intratae: &Tensor3D, exspectatae: &Tensor3D) -> f64 {
-    let mut totalis = 0.0;
-    let n = intratae.profunditas as f64;
+/// Magnitudo minibatch praefinita cum normalizatione per batch, quae statisticas plurium exemplorum requirit.
+/// Taille de mini-lot par défaut avec normalisation par lot, qui exige les statistiques de plusieurs exemples.
+pub const MAGNITUDO_BATCH_NORMALIZATIONIS: usize = 32;
+
+/// Larva omissionis [ordines x columnae]: 0 cum probabilitate p, alioquin 1 / (1 − p).
+/// Masque de dropout [lignes x colonnes] : 0 avec probabilité p, sinon 1 / (1 − p).
+fn larva_omissionis<T: Numerus>(ordines: usize, columnae: usize, p: f64, aleator: &mut dyn RngCore) -> TensorT2D<T> {
+    assert!((0.0..1.0).contains(&p), "Probabilitas omissionis in [0, 1) esse debet.");
+    let servatum = T::ex_f64(1.0 / (1.0 - p));
+    let elementa = (0..ordines * columnae)
+        .map(|_| if aleator.random::<f64>() < p { T::NULLUS } else { servatum })
+        .collect();
+    TensorT2D { elementa, ordines, columnae }
+}
 
-    for i in 0..intratae.profunditas {
-        let inputum = Tensor1D::ex_vec(intratae.materia[i][0].clone());
-        let exspectatum = Tensor1D::ex_vec(exspectatae.materia[i][0].clone());
-        let praedictum = self.praedictio(&inputum);
-        totalis += Self::perditio_mse(&praedictum, &exspectatum);
+/// Unus passus optimizatoris super omnia strata.
+/// Un pas de l’optimiseur sur toutes les couches.
+fn renova_strata<T: Numerus>(
+    strata: &mut [StratumDensum<T>],
+    gradientes: &Gradientes<T>,
+    optimizator: Optimizator,
+    status: &mut StatusOptimizatoris<T>,
+    celeritas: f64,
+) {
+    assert_eq!(strata.len(), gradientes.strata.len(), "Gradientes cum stratis non congruunt.");
+
+    // Status optimizatoris pro omni strato praeparatur.
+    // L’état de l’optimiseur est préparé pour chaque couche.
+    status.strata.resize_with(strata.len(), StatusStrati::default);
+    status.passus += 1;
+    let passus = status.passus;
+
+    for ((stratum, status_strati), gradiens) in strata.iter_mut().zip(status.strata.iter_mut()).zip(&gradientes.strata) {
+        optimizator.renova_tensor2d(&mut stratum.pesi, &gradiens.pesi, &mut status_strati.pesi, celeritas, passus);
+        optimizator.renova_tensor1d(&mut stratum.bias, &gradiens.bias, &mut status_strati.bias, celeritas, passus);
+        if let (Some(normalizatio), Some(gradiens)) = (&mut stratum.normalizatio, &gradiens.normalizatio) {
+            let (scala, translatio) = normalizatio.parametra_mut();
+            optimizator.renova_tensor1d(scala, &gradiens.scala, &mut status_strati.scala, celeritas, passus);
+            optimizator.renova_tensor1d(translatio, &gradiens.translatio, &mut status_strati.translatio, celeritas, passus);
+        }
     }
+}
 
-    totalis / n
+/// Compone matricem [magnitudo x exempla] ex exemplis `initium..finis` tensoris [exempla][1][magnitudo].
+/// Construit une matrice [taille x exemples] à partir des exemples `initium..finis`.
+fn columnae_exemplorum<T: Numerus>(data: &TensorT3D<T>, initium: usize, finis: usize) -> TensorT2D<T> {
+    let columnae: Vec<Vec<T>> = (initium..finis)
+        .map(|index| data.ordo(index, 0).to_vec())
+        .collect();
+    ex_columnis(&columnae)
 }
 
+/// Migra rete formae veteris (unum stratum occultum: `pesi_ih`, `bias_h`, `pesi_ho`, `bias_o`) in formam
+/// stratorum, cum duobus stratis sigmoideis ut antea. Rete formae novae non mutatur.
+/// Migre un réseau de l’ancien format (une couche cachée : `pesi_ih`, `bias_h`, `pesi_ho`, `bias_o`) vers le
+/// format en couches, avec deux couches sigmoïdes comme auparavant. Un réseau au format actuel n’est pas modifié.
+pub(crate) fn migra_formam_veterem(rete: &mut serde_json::Value) {
+    let Some(objectum) = rete.as_object_mut() else {
+        return;
+    };
+    let claves = ["pesi_ih", "bias_h", "pesi_ho", "bias_o"];
+    if objectum.contains_key("strata") || !claves.iter().all(|clavis| objectum.contains_key(*clavis)) {
+        return;
+    }
 
+    let mut stratum = |pesi, bias| serde_json::json!({ "pesi": objectum.remove(pesi), "bias": objectum.remove(bias) });
+    let strata = serde_json::json!([stratum("pesi_ih", "bias_h"), stratum("pesi_ho", "bias_o")]);
+    for clavis in ["input", "hidden", "output"] {
+        objectum.remove(clavis);
+    }
+    objectum.insert("strata".to_string(), strata);
 }
 
+/// Scribe nomen typi elementorum in serializatione.
+/// Écrit le nom du type d’élément lors de la sérialisation.
+fn scribe_praecisionem<T: Numerus, S: Serializer>(_: &PhantomData<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(T::NOMEN)
+}
+
+/// Lege nomen typi et verifica id typo retis convenire.
+/// Lit le nom du type et vérifie qu’il correspond au type du réseau.
+fn lege_praecisionem<'de, T: Numerus, D: Deserializer<'de>>(deserializer: D) -> Result<PhantomData<T>, D::Error> {
+    let nomen = String::deserialize(deserializer)?;
+    if nomen != T::NOMEN {
+        return Err(D::Error::custom(format!(
+            "Praecisio '{}' in archivo, sed '{}' exspectata (utere NeuronatusT::converte).",
+            nomen,
+            T::NOMEN
+        )));
+    }
+    Ok(PhantomData)
+}
@@ -0,0 +1,367 @@
+//
+// NORMALIZATIO
+// ------------
+// Normalisation par lot (batch-norm) et par couche (layer-norm) des sommes z d’une couche dense,
+// entre W·x + b et l’activation, avec échelle γ et décalage β appris.
+//
+
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use crate::gradientes::GradientesNormalizationis;
+use serde::{Serialize, Deserialize};
+
+/// Normalizatio summarum strati: y = γ ⊙ (z − μ) / √(σ² + ε) + β, cum γ (scala) et β (translatio) discibilibus.
+/// Normalisation des sommes d’une couche : y = γ ⊙ (z − μ) / √(σ² + ε) + β, avec γ (échelle) et β (décalage) appris.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Normalizatio<T = f64> {
+    /// Per batch: quaeque unitas per mediam et variantiam minibatch normatur (in disciplina);
+    /// in inferentia statisticae currentes (mediae mobiles, momento `momentum`) adhibentur.
+/// Par lot : chaque unité est normalisée par la moyenne et la variance du mini-lot (à l’entraînement) ;
+/// en inférence, les statistiques courantes (moyennes mobiles de facteur `momentum`) sont utilisées.
+    Batch {
+        scala: TensorT1D<T>,
+        translatio: TensorT1D<T>,
+        media_currens: TensorT1D<T>,
+        variantia_currens: TensorT1D<T>,
+        momentum: f64,
+        epsilon: f64,
+    },
+
+    /// Per stratum: quodque exemplum per mediam et variantiam unitatum suarum normatur,
+    /// eodem modo in disciplina et inferentia.
+/// Par couche : chaque exemple est normalisé par la moyenne et la variance de ses unités,
+/// de la même façon à l’entraînement et en inférence.
+    Strati {
+        scala: TensorT1D<T>,
+        translatio: TensorT1D<T>,
+        epsilon: f64,
+    },
+}
+
+/// Memoria propagationis antea, retropropagationi et statisticis currentibus necessaria.
+/// Mémoire de la propagation avant, nécessaire à la rétropropagation et aux statistiques courantes.
+#[derive(Debug, Clone)]
+pub(crate) struct MemoriaNormalizationis<T> {
+    normata: TensorT2D<T>, // x̂ = (z − μ) / √(σ² + ε) [unités x lot]
+    inversa: Vec<T>,       // 1 / √(σ² + ε) par groupe normalisé
+    media: Vec<T>,         // μ par groupe
+    variantia: Vec<T>,     // σ² (biaisée) par groupe
+    ex_batch: bool,        // Statistiques calculées sur le mini-lot (et non courantes)
+}
+
+impl<T: Numerus> Normalizatio<T> {
+    /// Normalizatio per batch pro `magnitudo` unitatibus (γ = 1, β = 0, momentum 0.1, ε = 10⁻⁵).
+/// Normalisation par lot pour `magnitudo` unités (γ = 1, β = 0, momentum 0.1, ε = 10⁻⁵).
+    pub fn batch(magnitudo: usize) -> Self {
+        Normalizatio::Batch {
+            scala: TensorT1D::ex_vec(vec![T::UNUS; magnitudo]),
+            translatio: TensorT1D::nullus(magnitudo),
+            media_currens: TensorT1D::nullus(magnitudo),
+            variantia_currens: TensorT1D::ex_vec(vec![T::UNUS; magnitudo]),
+            momentum: 0.1,
+            epsilon: 1e-5,
+        }
+    }
+
+    /// Normalizatio per stratum pro `magnitudo` unitatibus (γ = 1, β = 0, ε = 10⁻⁵).
+/// Normalisation par couche pour `magnitudo` unités (γ = 1, β = 0, ε = 10⁻⁵).
+    pub fn strati(magnitudo: usize) -> Self {
+        Normalizatio::Strati {
+            scala: TensorT1D::ex_vec(vec![T::UNUS; magnitudo]),
+            translatio: TensorT1D::nullus(magnitudo),
+            epsilon: 1e-5,
+        }
+    }
+
+    /// Numerus unitatum normatarum.
+/// Nombre d’unités normalisées.
+    pub fn magnitudo(&self) -> usize {
+        self.scala().magnitudo
+    }
+
+    /// Scala γ.
+/// Échelle γ.
+    pub fn scala(&self) -> &TensorT1D<T> {
+        match self {
+            Normalizatio::Batch { scala, .. } | Normalizatio::Strati { scala, .. } => scala,
+        }
+    }
+
+    /// Translatio β.
+/// Décalage β.
+    pub fn translatio(&self) -> &TensorT1D<T> {
+        match self {
+            Normalizatio::Batch { translatio, .. } | Normalizatio::Strati { translatio, .. } => translatio,
+        }
+    }
+
+    /// Parametra discibilia (γ, β) mutabilia, e.g. pro optimizatore.
+/// Paramètres appris (γ, β) modifiables, p. ex. pour l’optimiseur.
+    pub fn parametra_mut(&mut self) -> (&mut TensorT1D<T>, &mut TensorT1D<T>) {
+        match self {
+            Normalizatio::Batch { scala, translatio, .. } | Normalizatio::Strati { scala, translatio, .. } => {
+                (scala, translatio)
+            }
+        }
+    }
+
+    /// Converte normalizationem in alium typum numericum.
+/// Convertit la normalisation vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> Normalizatio<U> {
+        match self {
+            Normalizatio::Batch { scala, translatio, media_currens, variantia_currens, momentum, epsilon } => {
+                Normalizatio::Batch {
+                    scala: scala.converte(),
+                    translatio: translatio.converte(),
+                    media_currens: media_currens.converte(),
+                    variantia_currens: variantia_currens.converte(),
+                    momentum: *momentum,
+                    epsilon: *epsilon,
+                }
+            }
+            Normalizatio::Strati { scala, translatio, epsilon } => Normalizatio::Strati {
+                scala: scala.converte(),
+                translatio: translatio.converte(),
+                epsilon: *epsilon,
+            },
+        }
+    }
+
+    /// Normaliza summas [unitates x batch]; `disciplina` statisticas minibatch eligit (solum per batch).
+/// Normalise les sommes [unités x lot] ; `disciplina` choisit les statistiques du mini-lot (par lot seulement).
+    /// Minibatch unius exempli (e.g. residuum batch) variantiam nullam habet: statisticae currentes adhibentur nec renovantur.
+/// Un mini-lot d’un seul exemple (p. ex. le reste d’un lot) a une variance nulle : les statistiques courantes sont utilisées et non mises à jour.
+    pub(crate) fn antea(&self, summae: &TensorT2D<T>, disciplina: bool) -> (TensorT2D<T>, MemoriaNormalizationis<T>) {
+        assert_eq!(summae.ordines, self.magnitudo(), "Normalizatio cum strato non congruit.");
+
+        let memoria = match self {
+            Normalizatio::Batch { media_currens, variantia_currens, epsilon, .. } => {
+                if disciplina && summae.columnae > 1 {
+                    normaliza_ordines(summae, *epsilon)
+                } else {
+                    let epsilon = T::ex_f64(*epsilon);
+                    let inversa: Vec<T> =
+                        variantia_currens.materia.iter().map(|&v| T::UNUS / (v + epsilon).sqrt()).collect();
+                    let mut normata = summae.clone();
+                    for (i, (&media, &inv)) in media_currens.materia.iter().zip(&inversa).enumerate() {
+                        for x in normata.ordo_mut(i) {
+                            *x = (*x - media) * inv;
+                        }
+                    }
+                    MemoriaNormalizationis {
+                        normata,
+                        inversa,
+                        media: media_currens.materia.clone(),
+                        variantia: variantia_currens.materia.clone(),
+                        ex_batch: false,
+                    }
+                }
+            }
+            // Per stratum: columnae (exempla) normantur, i.e. ordines transpositae.
+            // Par couche : les colonnes (exemples) sont normalisées, i.e. les lignes de la transposée.
+            Normalizatio::Strati { epsilon, .. } => {
+                let mut memoria = normaliza_ordines(&summae.transpone(), *epsilon);
+                memoria.normata = memoria.normata.transpone();
+                memoria
+            }
+        };
+
+        let (scala, translatio) = (self.scala(), self.translatio());
+        let mut exitus = memoria.normata.clone();
+        for i in 0..exitus.ordines {
+            for y in exitus.ordo_mut(i) {
+                *y = scala.materia[i] * *y + translatio.materia[i];
+            }
+        }
+        (exitus, memoria)
+    }
+
+    /// Retropropagatio: ex ∂L/∂y reddit ∂L/∂z et gradientes γ, β.
+/// Rétropropagation : à partir de ∂L/∂y, renvoie ∂L/∂z et les gradients de γ et β.
+    pub(crate) fn retro(
+        &self,
+        memoria: &MemoriaNormalizationis<T>,
+        gradiens: &TensorT2D<T>,
+    ) -> (TensorT2D<T>, GradientesNormalizationis<T>) {
+        let gradientes = GradientesNormalizationis {
+            scala: gradiens.zip_map(&memoria.normata, |g, x| g * x).summa_ordinum(),
+            translatio: gradiens.summa_ordinum(),
+        };
+
+        // ∂L/∂x̂ = γ ⊙ ∂L/∂y
+        let scala = self.scala();
+        let mut normata = gradiens.clone();
+        for i in 0..normata.ordines {
+            for g in normata.ordo_mut(i) {
+                *g *= scala.materia[i];
+            }
+        }
+
+        let summae = match self {
+            Normalizatio::Batch { .. } if memoria.ex_batch => retro_ordines(&memoria.normata, &memoria.inversa, &normata),
+            // Statisticae currentes constantes sunt: transformatio affinis simplex.
+            // Les statistiques courantes sont constantes : simple transformation affine.
+            Normalizatio::Batch { .. } => {
+                for i in 0..normata.ordines {
+                    for g in normata.ordo_mut(i) {
+                        *g *= memoria.inversa[i];
+                    }
+                }
+                normata
+            }
+            Normalizatio::Strati { .. } => {
+                retro_ordines(&memoria.normata.transpone(), &memoria.inversa, &normata.transpone()).transpone()
+            }
+        };
+        (summae, gradientes)
+    }
+
+    /// Renova statisticas currentes ex statisticis minibatch: s ← (1 − m)·s + m·s_batch,
+    /// cum variantia non distorta (n / (n − 1)).
+/// Met à jour les statistiques courantes à partir de celles du mini-lot : s ← (1 − m)·s + m·s_lot,
+/// avec la variance non biaisée (n / (n − 1)).
+    pub(crate) fn renova_statisticas(&mut self, memoria: &MemoriaNormalizationis<T>) {
+        let Normalizatio::Batch { media_currens, variantia_currens, momentum, .. } = self else {
+            return;
+        };
+        if !memoria.ex_batch {
+            return;
+        }
+
+        let n = memoria.normata.columnae;
+        let correctio = T::ex_f64(if n > 1 { n as f64 / (n - 1) as f64 } else { 1.0 });
+        let (m, reliquum) = (T::ex_f64(*momentum), T::ex_f64(1.0 - *momentum));
+        for (currens, &media) in media_currens.materia.iter_mut().zip(&memoria.media) {
+            *currens = reliquum * *currens + m * media;
+        }
+        for (currens, &variantia) in variantia_currens.materia.iter_mut().zip(&memoria.variantia) {
+            *currens = reliquum * *currens + m * variantia * correctio;
+        }
+    }
+}
+
+/// Normaliza quemque ordinem per mediam et variantiam (distortam) suam.
+/// Normalise chaque ligne par sa moyenne et sa variance (biaisée).
+fn normaliza_ordines<T: Numerus>(summae: &TensorT2D<T>, epsilon: f64) -> MemoriaNormalizationis<T> {
+    let (epsilon, n) = (T::ex_f64(epsilon), T::ex_f64(summae.columnae as f64));
+    let mut normata = summae.clone();
+    let (mut inversa, mut media, mut variantia) = (Vec::new(), Vec::new(), Vec::new());
+
+    for i in 0..normata.ordines {
+        let ordo = normata.ordo_mut(i);
+        let mu = ordo.iter().copied().sum::<T>() / n;
+        let sigma2 = ordo.iter().map(|&x| (x - mu) * (x - mu)).sum::<T>() / n;
+        let inv = T::UNUS / (sigma2 + epsilon).sqrt();
+        for x in ordo.iter_mut() {
+            *x = (*x - mu) * inv;
+        }
+        inversa.push(inv);
+        media.push(mu);
+        variantia.push(sigma2);
+    }
+
+    MemoriaNormalizationis { normata, inversa, media, variantia, ex_batch: true }
+}
+
+/// Retropropagatio per normalizationem ordinum cum statisticis ex ipsis ordinibus:
+/// ∂L/∂z = inv / n · (n·∂L/∂x̂ − Σ ∂L/∂x̂ − x̂ · Σ(∂L/∂x̂ ⊙ x̂)).
+/// Rétropropagation à travers la normalisation des lignes, statistiques calculées sur ces lignes :
+/// ∂L/∂z = inv / n · (n·∂L/∂x̂ − Σ ∂L/∂x̂ − x̂ · Σ(∂L/∂x̂ ⊙ x̂)).
+fn retro_ordines<T: Numerus>(normata: &TensorT2D<T>, inversa: &[T], gradiens: &TensorT2D<T>) -> TensorT2D<T> {
+    let n = T::ex_f64(normata.columnae as f64);
+    let mut summae = gradiens.clone();
+
+    for (i, &inv) in inversa.iter().enumerate() {
+        let x = normata.ordo(i);
+        let summa = gradiens.ordo(i).iter().copied().sum::<T>();
+        let summa_x = gradiens.ordo(i).iter().zip(x).map(|(&g, &x)| g * x).sum::<T>();
+        for (g, &x) in summae.ordo_mut(i).iter_mut().zip(x) {
+            *g = inv / n * (n * *g - summa - x * summa_x);
+        }
+    }
+    summae
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+    use minitensor::Tensor2D;
+
+    /// Summae [unitates x batch]: tres unitates, quattuor exempla.
+    /// Sommes [unités x lot] : trois unités, quatre exemples.
+    fn summae() -> Tensor2D {
+        Tensor2D::ex_vec(vec![
+            vec![0.2, 0.8, 0.5, -0.3],
+            vec![0.6, -0.4, 0.1, 0.9],
+            vec![1.5, 0.3, -0.7, 0.2],
+        ])
+    }
+
+    /// Probat per batch quamque unitatem mediam 0 et variantiam ≈ 1 habere.
+    /// Teste que par lot chaque unité a une moyenne 0 et une variance ≈ 1.
+    #[test]
+    fn probatio_batch_normat_unitates() {
+        let (normata, _) = Normalizatio::batch(3).antea(&summae(), true);
+        for i in 0..normata.ordines {
+            let ordo = normata.ordo(i);
+            let media = ordo.iter().sum::<f64>() / 4.0;
+            let variantia = ordo.iter().map(|x| (x - media).powi(2)).sum::<f64>() / 4.0;
+            assert!(media.abs() < 1e-12 && (variantia - 1.0).abs() < 1e-3);
+        }
+    }
+
+    /// Probat per stratum quodque exemplum mediam 0 habere.
+    /// Teste que par couche chaque exemple a une moyenne 0.
+    #[test]
+    fn probatio_strati_normat_exempla() {
+        let (normata, _) = Normalizatio::strati(3).antea(&summae(), true);
+        for j in 0..normata.columnae {
+            assert!(normata.columna(j).materia.iter().sum::<f64>().abs() < 1e-12);
+        }
+    }
+
+    /// Probat statisticas currentes renovari: μ ← 0.9·μ + 0.1·μ_batch, variantia non distorta.
+    /// Teste la mise à jour des statistiques courantes : μ ← 0.9·μ + 0.1·μ_lot, variance non biaisée.
+    #[test]
+    fn probatio_renova_statisticas() {
+        let mut normalizatio = Normalizatio::batch(3);
+        let (_, memoria) = normalizatio.antea(&summae(), true);
+        normalizatio.renova_statisticas(&memoria);
+
+        let Normalizatio::Batch { media_currens, variantia_currens, .. } = &normalizatio else { unreachable!() };
+        for i in 0..3 {
+            let ordo = summae().ordo(i).to_vec();
+            let media = ordo.iter().sum::<f64>() / 4.0;
+            let variantia = ordo.iter().map(|x| (x - media).powi(2)).sum::<f64>() / 3.0;
+            assert!((media_currens.materia[i] - 0.1 * media).abs() < 1e-12);
+            assert!((variantia_currens.materia[i] - (0.9 + 0.1 * variantia)).abs() < 1e-12);
+        }
+    }
+
+    /// Probat in inferentia statisticas currentes adhiberi nec renovari.
+    /// Teste qu’en inférence les statistiques courantes sont utilisées et non mises à jour.
+    #[test]
+    fn probatio_inferentia() {
+        let mut normalizatio = Normalizatio::batch(3);
+        let (exitus, memoria) = normalizatio.antea(&summae(), false);
+        let inversa = 1.0 / (1.0 + 1e-5_f64).sqrt();
+        assert!((&exitus - &summae().map(|z| z * inversa)).norma_maxima() < 1e-12);
+
+        let ante = normalizatio.clone();
+        normalizatio.renova_statisticas(&memoria);
+        assert_eq!(normalizatio, ante);
+    }
+
+    /// Probat minibatch unius exempli in disciplina statisticas currentes adhibere nec renovare.
+    /// Teste qu’un mini-lot d’un seul exemple utilise à l’entraînement les statistiques courantes sans les mettre à jour.
+    #[test]
+    fn probatio_exemplum_singulum() {
+        let mut normalizatio = Normalizatio::batch(3);
+        let unum = summae().seca_columnas(0..1);
+        let (exitus, memoria) = normalizatio.antea(&unum, true);
+        assert_eq!(exitus, normalizatio.antea(&unum, false).0);
+
+        let ante = normalizatio.clone();
+        normalizatio.renova_statisticas(&memoria);
+        assert_eq!(normalizatio, ante);
+    }
+}
@@ -0,0 +1,269 @@
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use serde::{Serialize, Deserialize};
+
+/// Methodus renovandi parametra ex gradientibus.
+/// Méthode de mise à jour des paramètres à partir des gradients.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Optimizator {
+    /// Descensus gradientis simplex: θ ← θ − η·g.
+/// Descente de gradient simple : θ ← θ − η·g.
+    #[default]
+    Sgd,
+    /// Descensus cum momento: v ← μ·v + g, θ ← θ − η·v.
+/// Descente avec momentum : v ← μ·v + g, θ ← θ − η·v.
+    Momentum { momentum: f64 },
+    /// Momentum Nesterov: v ← μ·v + g, θ ← θ − η·(g + μ·v).
+/// Momentum de Nesterov : v ← μ·v + g, θ ← θ − η·(g + μ·v).
+    Nesterov { momentum: f64 },
+    /// RMSProp: s ← ρ·s + (1 − ρ)·g², θ ← θ − η·g / (√s + ε).
+/// RMSProp : s ← ρ·s + (1 − ρ)·g², θ ← θ − η·g / (√s + ε).
+    RmsProp { rho: f64, epsilon: f64 },
+    /// Adam cum correctione inclinationis momentorum.
+/// Adam avec correction du biais des moments.
+    Adam { beta1: f64, beta2: f64, epsilon: f64 },
+    /// Adam cum decessione ponderum separata (decoupled weight decay).
+/// Adam avec décroissance des poids découplée (decoupled weight decay).
+    AdamW { beta1: f64, beta2: f64, epsilon: f64, decessio: f64 },
+}
+
+/// Status unius parametri: momentum primum et secundum.
+/// État d’un paramètre : premier et second moments.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StatusParametri<T = f64> {
+    pub primum: Vec<T>,      // Vitesse / premier moment
+    pub secundum: Vec<T>,    // Second moment
+}
+
+/// Status optimizatoris pro uno strato.
+/// État de l’optimiseur pour une couche.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StatusStrati<T = f64> {
+    pub pesi: StatusParametri<T>,
+    pub bias: StatusParametri<T>,
+    #[serde(default = "status_vacuus")]
+    pub scala: StatusParametri<T>,       // γ de la normalisation (vide sans normalisation)
+    #[serde(default = "status_vacuus")]
+    pub translatio: StatusParametri<T>,  // β de la normalisation (vide sans normalisation)
+}
+
+/// Status totius optimizatoris, cum rete serializatus.
+/// État complet de l’optimiseur, sérialisé avec le réseau.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StatusOptimizatoris<T = f64> {
+    pub passus: u64,               // Nombre de mises à jour effectuées
+    pub strata: Vec<StatusStrati<T>>,
+}
+
+impl Optimizator {
+    /// Momentum cum μ = 0.9.
+/// Momentum avec μ = 0.9.
+    pub fn momentum() -> Self {
+        Optimizator::Momentum { momentum: 0.9 }
+    }
+
+    /// Nesterov cum μ = 0.9.
+/// Nesterov avec μ = 0.9.
+    pub fn nesterov() -> Self {
+        Optimizator::Nesterov { momentum: 0.9 }
+    }
+
+    /// RMSProp cum ρ = 0.9.
+/// RMSProp avec ρ = 0.9.
+    pub fn rmsprop() -> Self {
+        Optimizator::RmsProp { rho: 0.9, epsilon: 1e-8 }
+    }
+
+    /// Adam cum valoribus usitatis (β1 = 0.9, β2 = 0.999).
+/// Adam avec les valeurs usuelles (β1 = 0.9, β2 = 0.999).
+    pub fn adam() -> Self {
+        Optimizator::Adam { beta1: 0.9, beta2: 0.999, epsilon: 1e-8 }
+    }
+
+    /// AdamW cum valoribus usitatis et decessione 0.01.
+/// AdamW avec les valeurs usuelles et une décroissance de 0.01.
+    pub fn adamw() -> Self {
+        Optimizator::AdamW { beta1: 0.9, beta2: 0.999, epsilon: 1e-8, decessio: 0.01 }
+    }
+
+    /// Renova matricem ponderum per gradientem eius.
+/// Met à jour une matrice de poids à partir de son gradient.
+    pub fn renova_tensor2d<T: Numerus>(
+        &self,
+        parametra: &mut TensorT2D<T>,
+        gradiens: &TensorT2D<T>,
+        status: &mut StatusParametri<T>,
+        celeritas: f64,
+        passus: u64,
+    ) {
+        assert_eq!(parametra.ordines, gradiens.ordines);
+        assert_eq!(parametra.columnae, gradiens.columnae);
+
+        status.praepara(parametra.elementa.len());
+        self.renova(
+            &mut parametra.elementa,
+            &gradiens.elementa,
+            &mut status.primum,
+            &mut status.secundum,
+            celeritas,
+            passus,
+        );
+    }
+
+    /// Renova vectorem (e.g. bias) per gradientem eius.
+/// Met à jour un vecteur (p. ex. un biais) à partir de son gradient.
+    pub fn renova_tensor1d<T: Numerus>(
+        &self,
+        parametra: &mut TensorT1D<T>,
+        gradiens: &TensorT1D<T>,
+        status: &mut StatusParametri<T>,
+        celeritas: f64,
+        passus: u64,
+    ) {
+        assert_eq!(parametra.magnitudo, gradiens.magnitudo);
+        status.praepara(parametra.magnitudo);
+        self.renova(
+            &mut parametra.materia,
+            &gradiens.materia,
+            &mut status.primum,
+            &mut status.secundum,
+            celeritas,
+            passus,
+        );
+    }
+
+    /// Regula renovationis elementaris; `passus` incipit ab 1.
+/// Règle de mise à jour élément par élément ; `passus` commence à 1.
+    fn renova<T: Numerus>(
+        &self,
+        parametra: &mut [T],
+        gradiens: &[T],
+        primum: &mut [T],
+        secundum: &mut [T],
+        celeritas: f64,
+        passus: u64,
+    ) {
+        let iter = parametra.iter_mut().zip(gradiens.iter().copied()).zip(primum.iter_mut().zip(secundum.iter_mut()));
+        let (celeritas, unus) = (T::ex_f64(celeritas), T::UNUS);
+
+        match *self {
+            Optimizator::Sgd => {
+                for ((theta, g), _) in iter {
+                    *theta -= celeritas * g;
+                }
+            }
+            Optimizator::Momentum { momentum } => {
+                let momentum = T::ex_f64(momentum);
+                for ((theta, g), (v, _)) in iter {
+                    *v = momentum * *v + g;
+                    *theta -= celeritas * *v;
+                }
+            }
+            Optimizator::Nesterov { momentum } => {
+                let momentum = T::ex_f64(momentum);
+                for ((theta, g), (v, _)) in iter {
+                    *v = momentum * *v + g;
+                    *theta -= celeritas * (g + momentum * *v);
+                }
+            }
+            Optimizator::RmsProp { rho, epsilon } => {
+                let (rho, epsilon) = (T::ex_f64(rho), T::ex_f64(epsilon));
+                for ((theta, g), (_, s)) in iter {
+                    *s = rho * *s + (unus - rho) * g * g;
+                    *theta -= celeritas * g / (s.sqrt() + epsilon);
+                }
+            }
+            Optimizator::Adam { beta1, beta2, epsilon } | Optimizator::AdamW { beta1, beta2, epsilon, .. } => {
+                let decessio = match *self {
+                    Optimizator::AdamW { decessio, .. } => decessio,
+                    _ => 0.0,
+                };
+                let correctio1 = T::ex_f64(1.0 - beta1.powi(passus as i32));
+                let correctio2 = T::ex_f64(1.0 - beta2.powi(passus as i32));
+                let (beta1, beta2) = (T::ex_f64(beta1), T::ex_f64(beta2));
+                let (epsilon, decessio) = (T::ex_f64(epsilon), T::ex_f64(decessio));
+
+                for ((theta, g), (m, v)) in iter {
+                    *m = beta1 * *m + (unus - beta1) * g;
+                    *v = beta2 * *v + (unus - beta2) * g * g;
+                    let m_hat = *m / correctio1;
+                    let v_hat = *v / correctio2;
+                    *theta -= celeritas * (m_hat / (v_hat.sqrt() + epsilon) + decessio * *theta);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Numerus> StatusOptimizatoris<T> {
+    /// Converte statum in alium typum numericum.
+/// Convertit l’état vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> StatusOptimizatoris<U> {
+        let converte = |status: &StatusParametri<T>| StatusParametri {
+            primum: status.primum.iter().map(|x| x.converte()).collect(),
+            secundum: status.secundum.iter().map(|x| x.converte()).collect(),
+        };
+        StatusOptimizatoris {
+            passus: self.passus,
+            strata: self
+                .strata
+                .iter()
+                .map(|s| StatusStrati {
+                    pesi: converte(&s.pesi),
+                    bias: converte(&s.bias),
+                    scala: converte(&s.scala),
+                    translatio: converte(&s.translatio),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: Numerus> StatusParametri<T> {
+    /// Praepara momenta nulla si status nondum magnitudinem parametri habet.
+/// Initialise les moments à zéro si l’état n’a pas encore la taille du paramètre.
+    fn praepara(&mut self, magnitudo: usize) {
+        if self.primum.len() != magnitudo {
+            self.primum = vec![T::NULLUS; magnitudo];
+        }
+        if self.secundum.len() != magnitudo {
+            self.secundum = vec![T::NULLUS; magnitudo];
+        }
+    }
+}
+
+/// Status vacuus (momenta nondum praeparata), pro archivis sine normalizatione.
+/// État vide (moments pas encore initialisés), pour les fichiers sans normalisation.
+fn status_vacuus<T>() -> StatusParametri<T> {
+    StatusParametri { primum: Vec::new(), secundum: Vec::new() }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+    use minitensor::Tensor1D;
+
+    /// Probat omnes optimizatores functionem quadraticam minuere.
+    /// Teste que chaque optimiseur minimise une fonction quadratique.
+    #[test]
+    fn probatio_optimizatores() {
+        for optimizator in [
+            Optimizator::Sgd,
+            Optimizator::momentum(),
+            Optimizator::nesterov(),
+            Optimizator::rmsprop(),
+            Optimizator::adam(),
+            Optimizator::adamw(),
+        ] {
+            // f(θ) = Σ (θ − 3)², ∇f = 2(θ − 3)
+            let mut theta = Tensor1D::ex_vec(vec![0.0, 10.0]);
+            let mut status = StatusParametri::default();
+            for passus in 1..=2000 {
+                let gradiens = Tensor1D::ex_vec(theta.materia.iter().map(|t| 2.0 * (t - 3.0)).collect());
+                optimizator.renova_tensor1d(&mut theta, &gradiens, &mut status, 0.01, passus);
+            }
+            for t in &theta.materia {
+                assert!((t - 3.0).abs() < 0.1, "{:?}: {}", optimizator, t);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::activatio::Activatio;
+use minitensor::Numerus;
+use serde::{Serialize, Deserialize};
+
+/// Limes infimus probabilitatis, ne logarithmus infinitus fiat.
+/// Borne inférieure des probabilités, pour éviter un logarithme infini.
+const EPSILON: f64 = 1e-12;
+
+/// Functio perditionis cum gradiente congruenti.
+/// Fonction de perte avec son gradient correspondant.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Perditio {
+    /// Error quadraticus medius (MSE).
+/// Erreur quadratique moyenne (MSE).
+    #[default]
+    ErrorQuadraticus,
+    /// Error absolutus medius (MAE).
+/// Erreur absolue moyenne (MAE).
+    ErrorAbsolutus,
+    /// Perditio Huber: quadratica prope nihilum, linearis ultra delta.
+/// Perte de Huber : quadratique près de zéro, linéaire au-delà de delta.
+    Huber { delta: f64 },
+    /// Perditio "hinge" pro exspectatis in {-1, 1}.
+/// Perte « hinge » pour des cibles dans {-1, 1}.
+    Cardo,
+    /// Entropia cruciata binaria (exspectata in [0, 1]).
+/// Entropie croisée binaire (cibles dans [0, 1]).
+    EntropiaBinaria,
+    /// Entropia cruciata categorica (exspectata distributio probabilitatis).
+/// Entropie croisée catégorielle (cible : distribution de probabilité).
+    EntropiaCategorica,
+}
+
+impl Perditio {
+    /// Valor perditionis inter praedictum et exspectatum.
+/// Valeur de la perte entre la prédiction et la cible.
+    pub fn valor<T: Numerus>(&self, praedictum: &[T], exspectatum: &[T]) -> T {
+        assert_eq!(praedictum.len(), exspectatum.len());
+        let n = T::ex_f64(praedictum.len() as f64);
+        let (nullus, unus, dimidium) = (T::NULLUS, T::UNUS, T::ex_f64(0.5));
+        let epsilon = T::ex_f64(EPSILON);
+        let paria = praedictum.iter().copied().zip(exspectatum.iter().copied());
+
+        match *self {
+            Perditio::ErrorQuadraticus => paria.map(|(y, t)| (y - t).powi(2)).sum::<T>() / n,
+            Perditio::ErrorAbsolutus => paria.map(|(y, t)| (y - t).abs()).sum::<T>() / n,
+            Perditio::Huber { delta } => {
+                let delta = T::ex_f64(delta);
+                paria
+                    .map(|(y, t)| {
+                        let r = (y - t).abs();
+                        if r <= delta { dimidium * r * r } else { delta * (r - dimidium * delta) }
+                    })
+                    .sum::<T>() / n
+            }
+            Perditio::Cardo => paria.map(|(y, t)| (unus - t * y).max(nullus)).sum::<T>() / n,
+            Perditio::EntropiaBinaria => paria
+                .map(|(y, t)| {
+                    let p = y.max(epsilon).min(unus - epsilon);
+                    -(t * p.ln() + (unus - t) * (unus - p).ln())
+                })
+                .sum::<T>() / n,
+            Perditio::EntropiaCategorica => paria
+                .map(|(y, t)| -t * y.max(epsilon).ln())
+                .sum(),
+        }
+    }
+
+    /// Gradiens perditionis respectu praedicti (∂L/∂y).
+/// Gradient de la perte par rapport à la prédiction (∂L/∂y).
+    pub fn gradiens<T: Numerus>(&self, praedictum: &[T], exspectatum: &[T]) -> Vec<T> {
+        assert_eq!(praedictum.len(), exspectatum.len());
+        let n = T::ex_f64(praedictum.len() as f64);
+        let (nullus, unus) = (T::NULLUS, T::UNUS);
+        let epsilon = T::ex_f64(EPSILON);
+        let paria = praedictum.iter().copied().zip(exspectatum.iter().copied());
+
+        match *self {
+            Perditio::ErrorQuadraticus => paria.map(|(y, t)| T::ex_f64(2.0) * (y - t) / n).collect(),
+            Perditio::ErrorAbsolutus => paria
+                .map(|(y, t)| if y == t { nullus } else { (y - t).signum() / n })
+                .collect(),
+            Perditio::Huber { delta } => {
+                let delta = T::ex_f64(delta);
+                paria
+                    .map(|(y, t)| (y - t).max(-delta).min(delta) / n)
+                    .collect()
+            }
+            Perditio::Cardo => paria
+                .map(|(y, t)| if t * y < unus { -t / n } else { nullus })
+                .collect(),
+            Perditio::EntropiaBinaria => paria
+                .map(|(y, t)| {
+                    let p = y.max(epsilon).min(unus - epsilon);
+                    (p - t) / (p * (unus - p)) / n
+                })
+                .collect(),
+            Perditio::EntropiaCategorica => paria
+                .map(|(y, t)| -t / y.max(epsilon))
+                .collect(),
+        }
+    }
+
+    /// Verum si perditio cum activatione ultimi strati coniungi potest
+    /// (entropia binaria cum sigmoide, categorica cum softmax).
+/// Vrai si la perte peut être fusionnée avec l’activation de la dernière couche.
+    pub fn fusa_cum(&self, activatio: &Activatio) -> bool {
+        matches!(
+            (self, activatio),
+            (Perditio::EntropiaBinaria, Activatio::Sigmoides)
+                | (Perditio::EntropiaCategorica, Activatio::Softmax)
+        )
+    }
+
+    /// Valor perditionis fusae, directe ex summis (logitis) ultimi strati computatus.
+/// Valeur de la perte fusionnée, calculée directement depuis les logits.
+    pub fn valor_ex_summis<T: Numerus>(&self, summae: &[T], exspectatum: &[T]) -> T {
+        assert_eq!(summae.len(), exspectatum.len());
+        let n = T::ex_f64(summae.len() as f64);
+        let paria = summae.iter().copied().zip(exspectatum.iter().copied());
+
+        match *self {
+            // max(z, 0) − z·t + ln(1 + e^-|z|)
+            Perditio::EntropiaBinaria => paria
+                .map(|(z, t)| z.max(T::NULLUS) - z * t + (-z.abs()).exp().ln_1p())
+                .sum::<T>() / n,
+            // Σ t_i · (log Σ e^z − z_i)
+            Perditio::EntropiaCategorica => {
+                let maximum = summae.iter().copied().fold(-T::INFINITUS, T::max);
+                let log_summa = maximum + summae.iter().map(|&z| (z - maximum).exp()).sum::<T>().ln();
+                paria.map(|(z, t)| t * (log_summa - z)).sum()
+            }
+            _ => panic!("Perditio {:?} cum activatione non fusa est.", self),
+        }
+    }
+
+    /// Gradiens perditionis fusae respectu summarum (∂L/∂z), ex probabilitatibus praedictis.
+/// Gradient de la perte fusionnée par rapport aux logits (∂L/∂z).
+    pub fn gradiens_summarum<T: Numerus>(&self, praedictum: &[T], exspectatum: &[T]) -> Vec<T> {
+        assert_eq!(praedictum.len(), exspectatum.len());
+        let n = T::ex_f64(praedictum.len() as f64);
+        let paria = praedictum.iter().copied().zip(exspectatum.iter().copied());
+
+        match *self {
+            Perditio::EntropiaBinaria => paria.map(|(p, t)| (p - t) / n).collect(),
+            Perditio::EntropiaCategorica => paria.map(|(p, t)| p - t).collect(),
+            _ => panic!("Perditio {:?} cum activatione non fusa est.", self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Probat gradientes perditionum contra differentias finitas.
+    /// Teste les gradients des pertes contre les différences finies.
+    #[test]
+    fn probatio_gradientes_numerici() {
+        let praedictum: [f64; 3] = [0.2, 0.7, 0.4];
+        let exspectatum = [0.0, 1.0, 0.0];
+        let h = 1e-6;
+
+        for perditio in [
+            Perditio::ErrorQuadraticus,
+            Perditio::ErrorAbsolutus,
+            Perditio::Huber { delta: 0.25 },
+            Perditio::EntropiaBinaria,
+            Perditio::EntropiaCategorica,
+        ] {
+            let gradiens = perditio.gradiens(&praedictum, &exspectatum);
+            for i in 0..praedictum.len() {
+                let (mut plus, mut minus) = (praedictum, praedictum);
+                plus[i] += h;
+                minus[i] -= h;
+                let numericus = (perditio.valor(&plus, &exspectatum) - perditio.valor(&minus, &exspectatum)) / (2.0 * h);
+                assert!((gradiens[i] - numericus).abs() < 1e-5, "{:?} in {}", perditio, i);
+            }
+        }
+    }
+
+    /// Probat valorem perditionis Cardo.
+    /// Teste la valeur de la perte Cardo.
+    #[test]
+    fn probatio_cardo() {
+        let cardo: f64 = Perditio::Cardo.valor(&[0.5, -2.0], &[1.0, -1.0]);
+        assert!((cardo - 0.25).abs() < 1e-12);
+    }
+
+    /// Probat perditionem fusam cum activatione idem valere ac non fusam.
+    /// Teste que la perte fusionnée vaut la perte calculée sur les probabilités.
+    #[test]
+    fn probatio_perditio_fusa() {
+        let summae: [f64; 3] = [1.5, -0.3, 0.2];
+        let exspectatum = [0.0, 1.0, 0.0];
+
+        for (perditio, activatio) in [
+            (Perditio::EntropiaBinaria, Activatio::Sigmoides),
+            (Perditio::EntropiaCategorica, Activatio::Softmax),
+        ] {
+            assert!(perditio.fusa_cum(&activatio));
+            let fusa = perditio.valor_ex_summis(&summae, &exspectatum);
+            let simplex = perditio.valor(&activatio.applica(&summae), &exspectatum);
+            assert!((fusa - simplex).abs() < 1e-12);
+        }
+    }
+
+    /// Probat logita extrema perditionem fusam finitam reddere.
+    /// Teste que des logits extrêmes donnent une perte fusionnée finie.
+    #[test]
+    fn probatio_logita_extrema() {
+        assert!(Perditio::EntropiaBinaria.valor_ex_summis(&[800.0_f64], &[0.0]).is_finite());
+    }
+}
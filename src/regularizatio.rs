@@ -0,0 +1,78 @@
+use minitensor::Numerus;
+use crate::gradientes::Gradientes;
+use crate::stratum::StratumDensum;
+use serde::{Serialize, Deserialize};
+
+/// Regularizatio ponderum: poena λ₁·Σ|w| + (λ₂/2)·Σw² perditioni additur.
+/// Régularisation des poids : la pénalité λ₁·Σ|w| + (λ₂/2)·Σw² est ajoutée à la perte.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Regularizatio {
+    pub l1: f64,     // λ₁ (lasso)
+    pub l2: f64,     // λ₂ (ridge) ; gradient λ₂·w, soit une décroissance des poids
+    #[serde(default)]
+    pub bias: bool,  // Pénaliser aussi les biais (faux par défaut)
+}
+
+impl Regularizatio {
+    /// Sola poena L1 in ponderibus.
+/// Pénalité L1 seule sur les poids.
+    pub fn l1(lambda: f64) -> Self {
+        Regularizatio { l1: lambda, ..Default::default() }
+    }
+
+    /// Sola poena L2 in ponderibus.
+/// Pénalité L2 seule sur les poids.
+    pub fn l2(lambda: f64) -> Self {
+        Regularizatio { l2: lambda, ..Default::default() }
+    }
+
+    /// Verum si aliqua poena non nulla est.
+/// Vrai si une pénalité est non nulle.
+    pub fn activa(&self) -> bool {
+        self.l1 != 0.0 || self.l2 != 0.0
+    }
+
+    /// Valor poenae pro stratis datis.
+/// Valeur de la pénalité pour les couches données.
+    pub fn poena<T: Numerus>(&self, strata: &[StratumDensum<T>]) -> T {
+        if !self.activa() {
+            return T::NULLUS;
+        }
+
+        let (l1, dimidium_l2) = (T::ex_f64(self.l1), T::ex_f64(self.l2 / 2.0));
+        strata
+            .iter()
+            .map(|stratum| {
+                let bias: &[T] = if self.bias { &stratum.bias.materia } else { &[] };
+                stratum.pesi.elementa.iter().chain(bias).map(|&w| l1 * w.abs() + dimidium_l2 * w * w).sum::<T>()
+            })
+            .sum()
+    }
+
+    /// Adde gradientem poenae (λ₁·sgn(w) + λ₂·w) gradientibus perditionis.
+/// Ajoute le gradient de la pénalité (λ₁·sgn(w) + λ₂·w) aux gradients de la perte.
+    pub fn adde_gradientes<T: Numerus>(&self, strata: &[StratumDensum<T>], gradientes: &mut Gradientes<T>) {
+        if !self.activa() {
+            return;
+        }
+
+        let (l1, l2) = (T::ex_f64(self.l1), T::ex_f64(self.l2));
+        let gradiens = |w: T| {
+            // sgn(0) = 0, ut subgradiens in puncto non differentiabili.
+            // sgn(0) = 0, comme sous-gradient au point non dérivable.
+            let signum = if w == T::NULLUS { T::NULLUS } else { w.signum() };
+            l1 * signum + l2 * w
+        };
+
+        for (stratum, gradiens_strati) in strata.iter().zip(&mut gradientes.strata) {
+            for (g, &w) in gradiens_strati.pesi.elementa.iter_mut().zip(&stratum.pesi.elementa) {
+                *g += gradiens(w);
+            }
+            if self.bias {
+                for (g, &b) in gradiens_strati.bias.materia.iter_mut().zip(&stratum.bias.materia) {
+                    *g += gradiens(b);
+                }
+            }
+        }
+    }
+}
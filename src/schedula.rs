@@ -0,0 +1,230 @@
+use serde::{Serialize, Deserialize};
+use std::f64::consts::PI;
+
+/// Schedula celeritatis discendi per epochas; `basis` est `Neuronatus::celeritas`.
+/// Planification du taux d’apprentissage par époque ; `basis` est `Neuronatus::celeritas`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Schedula {
+    /// η = basis per totam disciplinam.
+/// η = basis pendant tout l’entraînement.
+    #[default]
+    Constans,
+    /// η = basis · γ^⌊e / magnitudo⌋ (step decay).
+/// η = basis · γ^⌊e / magnitudo⌋ (décroissance par paliers).
+    Gradatim { magnitudo: usize, gamma: f64 },
+    /// η = basis · γ^e.
+/// η = basis · γ^e (décroissance exponentielle).
+    Exponentialis { gamma: f64 },
+    /// Cosinus cum resumptionibus calidis (SGDR): cyclus primus `periodus` epocharum,
+    /// quisque sequens `multiplicator` vicibus longior; η ab basis ad `minimum` descendit.
+/// Cosinus avec redémarrages à chaud (SGDR) : premier cycle de `periodus` époques,
+/// chaque suivant `multiplicator` fois plus long ; η descend de basis à `minimum`.
+    Cosinus { periodus: usize, multiplicator: usize, minimum: f64 },
+    /// Calefactio linearis: η a basis · `initium` ad basis per `epochae` epochas, deinde constans.
+/// Échauffement linéaire : η passe de basis · `initium` à basis en `epochae` époques, puis reste constant.
+    Calefactio { epochae: usize, initium: f64 },
+    /// Unus cyclus: η a basis / `divisor` ad basis per partem `ascensus` epocharum ascendit,
+    /// deinde ad basis / (`divisor` · `divisor_finalis`) descendit (utrumque per cosinum).
+/// One-cycle : η monte de basis / `divisor` à basis pendant la fraction `ascensus` des époques,
+/// puis descend à basis / (`divisor` · `divisor_finalis`) (les deux par cosinus).
+    UnusCyclus { epochae: usize, ascensus: f64, divisor: f64, divisor_finalis: f64 },
+    /// Reductio in planitie: si perditio per `patientia` epochas non decrescit plus quam
+    /// `tolerantia` (relative), η per `factor` multiplicatur, non infra `minimum`.
+/// Réduction sur plateau : si la perte ne baisse pas de plus de `tolerantia` (relatif) pendant
+/// `patientia` époques, η est multiplié par `factor`, sans descendre sous `minimum`.
+    InPlanitie { factor: f64, patientia: usize, tolerantia: f64, minimum: f64 },
+}
+
+/// Status schedulae, cum rete serializatus ut disciplina exacte resumatur.
+/// État de la planification, sérialisé avec le réseau pour reprendre l’entraînement à l’identique.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StatusSchedulae {
+    pub epocha: usize,             // Époques déjà effectuées
+    pub celeritas: Option<f64>,    // Taux courant (None avant la première époque)
+    pub optima: Option<f64>,       // Meilleure perte observée (réduction sur plateau)
+    pub mora: usize,               // Époques sans amélioration (réduction sur plateau)
+}
+
+impl Schedula {
+    /// Gradatim cum γ = 0.1 post omnes `magnitudo` epochas.
+/// Décroissance par paliers avec γ = 0.1 toutes les `magnitudo` époques.
+    pub fn gradatim(magnitudo: usize) -> Self {
+        Schedula::Gradatim { magnitudo, gamma: 0.1 }
+    }
+
+    /// Cosinus cum cyclis aequalibus et minimo nullo.
+/// Cosinus à cycles égaux et minimum nul.
+    pub fn cosinus(periodus: usize) -> Self {
+        Schedula::Cosinus { periodus, multiplicator: 1, minimum: 0.0 }
+    }
+
+    /// Unus cyclus cum valoribus usitatis (30% ascensus, divisor 25, divisor finalis 10⁴).
+/// One-cycle avec les valeurs usuelles (30 % de montée, diviseur 25, diviseur final 10⁴).
+    pub fn unus_cyclus(epochae: usize) -> Self {
+        Schedula::UnusCyclus { epochae, ascensus: 0.3, divisor: 25.0, divisor_finalis: 1e4 }
+    }
+
+    /// Reductio in planitie: η dimidiatur post 10 epochas sine progressu.
+/// Réduction sur plateau : η est divisé par deux après 10 époques sans progrès.
+    pub fn in_planitie() -> Self {
+        Schedula::InPlanitie { factor: 0.5, patientia: 10, tolerantia: 1e-4, minimum: 0.0 }
+    }
+
+    /// Verum si schedula perditionem cuiusque epochae requirit.
+/// Vrai si la planification a besoin de la perte de chaque époque.
+    pub fn perditionem_requirit(&self) -> bool {
+        matches!(self, Schedula::InPlanitie { .. })
+    }
+
+    /// Celeritas pro epocha `status.epocha`.
+/// Taux d’apprentissage pour l’époque `status.epocha`.
+    pub fn celeritas(&self, basis: f64, status: &StatusSchedulae) -> f64 {
+        let e = status.epocha;
+        match *self {
+            Schedula::Constans => basis,
+            Schedula::Gradatim { magnitudo, gamma } => basis * gamma.powi((e / magnitudo.max(1)) as i32),
+            Schedula::Exponentialis { gamma } => basis * gamma.powi(e as i32),
+            Schedula::Cosinus { periodus, multiplicator, minimum } => {
+                // Quaere cyclum currentem et positionem in eo.
+                // Cherche le cycle courant et la position dans celui-ci.
+                let (mut t, mut longitudo) = (e, periodus.max(1));
+                while t >= longitudo {
+                    t -= longitudo;
+                    longitudo *= multiplicator.max(1);
+                }
+                cosinus_interpolatio(basis, minimum, t as f64 / longitudo as f64)
+            }
+            Schedula::Calefactio { epochae, initium } => {
+                if e < epochae {
+                    basis * (initium + (1.0 - initium) * e as f64 / epochae as f64)
+                } else {
+                    basis
+                }
+            }
+            Schedula::UnusCyclus { epochae, ascensus, divisor, divisor_finalis } => {
+                let (initium, finis) = (basis / divisor, basis / (divisor * divisor_finalis));
+                let culmen = ((epochae as f64 * ascensus).round() as usize).max(1);
+                // Ultima epocha (epochae − 1) ad finem pervenit.
+                // La dernière époque (epochae − 1) atteint la valeur finale.
+                let descensus = epochae.saturating_sub(culmen + 1).max(1);
+                if e < culmen {
+                    cosinus_interpolatio(initium, basis, e as f64 / culmen as f64)
+                } else {
+                    cosinus_interpolatio(basis, finis, ((e - culmen) as f64 / descensus as f64).min(1.0))
+                }
+            }
+            Schedula::InPlanitie { .. } => status.celeritas.unwrap_or(basis),
+        }
+    }
+
+    /// Finis epochae: perditionem observa (pro reductione in planitie) et epocham auge.
+/// Fin d’époque : observe la perte (pour la réduction sur plateau) et incrémente l’époque.
+    pub fn observa(&self, status: &mut StatusSchedulae, perditio: Option<f64>) {
+        status.epocha += 1;
+
+        let Schedula::InPlanitie { factor, patientia, tolerantia, minimum } = *self else {
+            return;
+        };
+        let Some(perditio) = perditio else {
+            return;
+        };
+
+        match status.optima {
+            Some(optima) if perditio >= optima * (1.0 - tolerantia) => {
+                status.mora += 1;
+                if status.mora > patientia {
+                    let currens = status.celeritas.unwrap_or(0.0);
+                    status.celeritas = Some((currens * factor).max(minimum));
+                    status.mora = 0;
+                }
+            }
+            _ => {
+                status.optima = Some(perditio);
+                status.mora = 0;
+            }
+        }
+    }
+}
+
+/// Interpolatio cosina ab `initium` (t = 0) ad `finis` (t = 1).
+/// Interpolation en cosinus de `initium` (t = 0) à `finis` (t = 1).
+fn cosinus_interpolatio(initium: f64, finis: f64, t: f64) -> f64 {
+    finis + (initium - finis) * (1.0 + (PI * t).cos()) / 2.0
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Celeritates primarum `epochae` epocharum, basi 1 et sine perditione observata.
+    /// Taux des `epochae` premières époques, de base 1 et sans perte observée.
+    fn celeritates(schedula: Schedula, epochae: usize) -> Vec<f64> {
+        let mut status = StatusSchedulae::default();
+        (0..epochae)
+            .map(|_| {
+                let celeritas = schedula.celeritas(1.0, &status);
+                schedula.observa(&mut status, None);
+                celeritas
+            })
+            .collect()
+    }
+
+    fn prope(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
+    }
+
+    /// Probat schedulam gradatim per decadas minuere.
+    /// Teste que la planification par paliers divise par dix.
+    #[test]
+    fn probatio_gradatim() {
+        assert_eq!(celeritates(Schedula::gradatim(2), 5), vec![1.0, 1.0, 0.1, 0.1, 0.1 * 0.1]);
+    }
+
+    /// Probat schedulam exponentialem.
+    /// Teste la planification exponentielle.
+    #[test]
+    fn probatio_exponentialis() {
+        assert!(prope(celeritates(Schedula::Exponentialis { gamma: 0.5 }, 4)[3], 0.125));
+    }
+
+    /// Probat calefactionem linearem ad basim.
+    /// Teste l’échauffement linéaire jusqu’à la base.
+    #[test]
+    fn probatio_calefactio() {
+        assert_eq!(celeritates(Schedula::Calefactio { epochae: 4, initium: 0.0 }, 6), vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0]);
+    }
+
+    /// Probat cosinum: cycli 2, deinde 4 epocharum; in initio cuiusque cycli η = basis.
+    /// Teste le cosinus : cycles de 2 puis 4 époques ; η = base au début de chaque cycle.
+    #[test]
+    fn probatio_cosinus() {
+        let cosinus = celeritates(Schedula::Cosinus { periodus: 2, multiplicator: 2, minimum: 0.0 }, 7);
+        assert!(prope(cosinus[0], 1.0) && prope(cosinus[1], 0.5));
+        assert!(prope(cosinus[2], 1.0) && prope(cosinus[4], 0.5) && prope(cosinus[6], 1.0));
+    }
+
+    /// Probat unum cyclum a basis / 25 ad basis ascendere, deinde infra initium descendere.
+    /// Teste que le one-cycle monte de base / 25 à base, puis redescend sous le départ.
+    #[test]
+    fn probatio_unus_cyclus() {
+        let cyclus = celeritates(Schedula::unus_cyclus(10), 10);
+        assert!(prope(cyclus[0], 1.0 / 25.0) && prope(cyclus[3], 1.0));
+        assert!(prope(cyclus[9], 1.0 / 25e4) && cyclus.windows(2).skip(3).all(|par| par[1] < par[0]));
+    }
+
+    /// Probat reductionem in planitie: post `patientia` epochas sine progressu η dimidiatur, non infra minimum.
+    /// Teste la réduction sur plateau : après `patientia` époques sans progrès η est divisé par deux, sans passer sous le minimum.
+    #[test]
+    fn probatio_in_planitie() {
+        let planities = Schedula::InPlanitie { factor: 0.5, patientia: 2, tolerantia: 0.0, minimum: 0.1 };
+        let mut status = StatusSchedulae { celeritas: Some(1.0), ..Default::default() };
+        for perditio in [1.0, 0.9, 0.9, 0.95, 0.9] {
+            planities.observa(&mut status, Some(perditio));
+        }
+        assert_eq!(status.celeritas, Some(0.5));
+        for _ in 0..9 {
+            planities.observa(&mut status, Some(1.0));
+        }
+        assert_eq!(status.celeritas, Some(0.1));
+    }
+}
@@ -0,0 +1,241 @@
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use crate::activatio::Activatio;
+use crate::initialisatio::Initialisatio;
+use crate::normalizatio::{MemoriaNormalizationis, Normalizatio};
+use rand::Rng;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error as _;
+
+/// Stratum densum: omnis neuron cum omni neurone strati prioris conectitur.
+/// Couche dense : chaque neurone est relié à tous ceux de la couche précédente.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StratumDensum<T = f64> {
+    pub pesi: TensorT2D<T>,    // Poids [sortie x entrée]
+    pub bias: TensorT1D<T>,    // Biais de la couche
+
+    #[serde(default)]
+    pub activatio: Activatio, // Fonction d’activation de la couche
+
+    #[serde(default, deserialize_with = "lege_omissionem")]
+    pub omissio: f64,         // Probabilité de dropout sur la sortie (couches cachées, entraînement seulement)
+
+    #[serde(default)]
+    pub normalizatio: Option<Normalizatio<T>>,  // Normalisation de z avant l’activation (batch-norm ou layer-norm)
+}
+
+impl<T: Numerus> StratumDensum<T> {
+    /// Crea novum stratum cum activatione data: pondera per `Initialisatio::pro_activatione`, biases nulli.
+/// Crée une couche avec la fonction d’activation donnée : poids selon `Initialisatio::pro_activatione`, biais nuls.
+    pub fn novus(introitus: usize, exitus: usize, activatio: Activatio) -> Self {
+        Self::novus_cum(introitus, exitus, activatio, &mut rand::rng())
+    }
+
+    /// Crea novum stratum cuius pondera ex generatore dato trahuntur (primum pesi, deinde bias).
+/// Crée une couche dont les poids sont tirés du générateur donné (d’abord les poids, puis le biais).
+    pub fn novus_cum<R: Rng + ?Sized>(introitus: usize, exitus: usize, activatio: Activatio, aleator: &mut R) -> Self {
+        let pesi = Initialisatio::pro_activatione(activatio);
+        Self::initialisatus(introitus, exitus, activatio, pesi, Initialisatio::Nullus, aleator)
+    }
+
+    /// Crea novum stratum cum rationibus initialisationis datis pro ponderibus et biasibus,
+    /// e.g. `HeNormalis` et `Nullus` pro ReLU.
+/// Crée une couche avec les schémas d’initialisation donnés pour les poids et les biais,
+/// p. ex. `HeNormalis` et `Nullus` pour ReLU.
+    pub fn initialisatus<R: Rng + ?Sized>(
+        introitus: usize,
+        exitus: usize,
+        activatio: Activatio,
+        pesi: Initialisatio,
+        bias: Initialisatio,
+        aleator: &mut R,
+    ) -> Self {
+        Self {
+            pesi: pesi.pondera(exitus, introitus, aleator),
+            bias: bias.bias(exitus, introitus, aleator),
+            activatio,
+            omissio: 0.0,
+            normalizatio: None,
+        }
+    }
+
+    /// Idem stratum cum probabilitate omissionis `p` in exitu (solum in disciplina); `p` in [0, 1) esse debet.
+/// La même couche avec une probabilité de dropout `p` en sortie (à l’entraînement seulement) ; `p` doit être dans [0, 1).
+    pub fn cum_omissione(self, p: f64) -> Self {
+        verifica_omissionem(p);
+        Self { omissio: p, ..self }
+    }
+
+    /// Idem stratum cum normalizatione summarum ante activationem, e.g. `Normalizatio::batch(exitus)`.
+/// La même couche avec normalisation des sommes avant l’activation, p. ex. `Normalizatio::batch(exitus)`.
+    pub fn cum_normalizatione(self, normalizatio: Normalizatio<T>) -> Self {
+        assert_eq!(normalizatio.magnitudo(), self.exitus(), "Normalizatio cum strato non congruit.");
+        Self { normalizatio: Some(normalizatio), ..self }
+    }
+
+    /// Converte stratum in alium typum numericum.
+/// Convertit la couche vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> StratumDensum<U> {
+        StratumDensum {
+            pesi: self.pesi.converte(),
+            bias: self.bias.converte(),
+            activatio: self.activatio,
+            omissio: self.omissio,
+            normalizatio: self.normalizatio.as_ref().map(|normalizatio| normalizatio.converte()),
+        }
+    }
+
+    /// Numerus inputorum strati.
+/// Nombre d’entrées de la couche.
+    pub fn introitus(&self) -> usize {
+        self.pesi.columnae
+    }
+
+    /// Numerus neuronum (exituum) strati.
+/// Nombre de neurones (sorties) de la couche.
+    pub fn exitus(&self) -> usize {
+        self.pesi.ordines
+    }
+
+    /// Summa ponderata W·X + b ante activationem; columnae X sunt exempla.
+/// Somme pondérée W·X + b avant l’activation ; chaque colonne de X est un exemple.
+    pub fn summa(&self, intratae: &TensorT2D<T>) -> TensorT2D<T> {
+        // Bias ut columna [exitus x 1] ad omnem columnam (exemplum) diffunditur.
+        // Le biais, vu comme colonne [sortie x 1], est diffusé sur chaque colonne (exemple).
+        self.pesi.productum_matriciale(intratae) + self.bias.transpone()
+    }
+
+    /// Summa normata (si normalizatio adest), i.e. argumentum activationis, cum memoria retropropagationis;
+    /// `disciplina` statisticas minibatch pro normalizatione per batch eligit.
+/// Somme normalisée (s’il y a une normalisation), i.e. l’argument de l’activation, avec la mémoire
+/// de rétropropagation ; `disciplina` choisit les statistiques du mini-lot pour la normalisation par lot.
+    pub(crate) fn summa_normata(
+        &self,
+        intratae: &TensorT2D<T>,
+        disciplina: bool,
+    ) -> (TensorT2D<T>, Option<MemoriaNormalizationis<T>>) {
+        let summa = self.summa(intratae);
+        match &self.normalizatio {
+            Some(normalizatio) => {
+                let (normata, memoria) = normalizatio.antea(&summa, disciplina);
+                (normata, Some(memoria))
+            }
+            None => (summa, None),
+        }
+    }
+
+    /// Propagatio antea (inferentia): matrix inputi [introitus x batch] → activationes [exitus x batch].
+/// Propagation avant (inférence) : entrées [entrée x lot] → activations [sortie x lot].
+    pub fn antea(&self, intratae: &TensorT2D<T>) -> TensorT2D<T> {
+        per_columnas(&self.summa_normata(intratae, false).0, |summa| self.activatio.applica(summa))
+    }
+}
+
+/// Extrahe columnam `j` matricis in vectorem.
+/// Extrait la colonne `j` d’une matrice dans un vecteur.
+pub(crate) fn columna<T: Numerus>(tensor: &TensorT2D<T>, j: usize) -> Vec<T> {
+    (0..tensor.ordines).map(|i| tensor[(i, j)]).collect()
+}
+
+/// Compone matricem ex columnis aequae longitudinis.
+/// Construit une matrice à partir de colonnes de même longueur.
+pub(crate) fn ex_columnis<T: Numerus>(columnae: &[Vec<T>]) -> TensorT2D<T> {
+    let ordines = columnae[0].len();
+    let elementa = (0..ordines)
+        .flat_map(|i| columnae.iter().map(move |columna| columna[i]))
+        .collect();
+    TensorT2D::ex_elementis(ordines, columnae.len(), elementa)
+}
+
+/// Applica functionem vectorialem ad omnem columnam (exemplum).
+/// Applique une fonction vectorielle à chaque colonne (exemple).
+pub(crate) fn per_columnas<T: Numerus>(tensor: &TensorT2D<T>, f: impl Fn(&[T]) -> Vec<T>) -> TensorT2D<T> {
+    let columnae: Vec<Vec<T>> = (0..tensor.columnae).map(|j| f(&columna(tensor, j))).collect();
+    ex_columnis(&columnae)
+}
+
+/// Applica functionem ad paria columnarum duarum matricum eiusdem formae.
+/// Applique une fonction aux paires de colonnes de deux matrices de même forme.
+pub(crate) fn per_columnas_bina<T: Numerus>(
+    a: &TensorT2D<T>,
+    b: &TensorT2D<T>,
+    f: impl Fn(&[T], &[T]) -> Vec<T>,
+) -> TensorT2D<T> {
+    assert_eq!(a.columnae, b.columnae);
+    let columnae: Vec<Vec<T>> = (0..a.columnae)
+        .map(|j| f(&columna(a, j), &columna(b, j)))
+        .collect();
+    ex_columnis(&columnae)
+}
+
+/// Probabilitas omissionis in [0, 1) esse debet (p ≥ 1 omnes unitates omitteret, NaN nullam larvam daret).
+/// La probabilité de dropout doit être dans [0, 1) (p ≥ 1 supprimerait toutes les unités, NaN aucun masque).
+pub(crate) fn verifica_omissionem(p: f64) {
+    assert!((0.0..1.0).contains(&p), "Probabilitas omissionis in [0, 1) esse debet, {} data.", p);
+}
+
+/// Lege probabilitatem omissionis et verifica eam in [0, 1) esse.
+/// Lit la probabilité de dropout et vérifie qu’elle est dans [0, 1).
+fn lege_omissionem<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let p = f64::deserialize(deserializer)?;
+    if !(0.0..1.0).contains(&p) {
+        return Err(D::Error::custom(format!("Probabilitas omissionis in [0, 1) esse debet, {} data.", p)));
+    }
+    Ok(p)
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+    use minitensor::Tensor1D;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Probat activationem strati in serializatione servari.
+    /// Teste que l’activation d’une couche est conservée par la sérialisation.
+    #[test]
+    fn probatio_activatio_serializata() {
+        let stratum = StratumDensum::novus(3, 2, Activatio::Elu { alpha: 0.5 });
+        let json = serde_json::to_string(&stratum).unwrap();
+        let restitutum: StratumDensum = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restitutum.activatio, Activatio::Elu { alpha: 0.5 });
+        let intrata = Tensor1D::ex_vec(vec![0.3, -0.2, 0.9]).transpone();
+        let (a, b) = (restitutum.antea(&intrata), stratum.antea(&intrata));
+        for (x, y) in a.elementa.iter().zip(&b.elementa) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    /// Probat initialisationem per stratum, cum biasibus nullis.
+    /// Teste l’initialisation par couche, avec des biais nuls.
+    #[test]
+    fn probatio_initialisatus() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        let stratum: StratumDensum = StratumDensum::initialisatus(
+            8, 4, Activatio::Relu, Initialisatio::HeUniformis, Initialisatio::Nullus, &mut aleator,
+        );
+        assert!(stratum.bias.materia.iter().all(|&b| b == 0.0));
+        assert!(stratum.pesi.minimum() < 0.0 && stratum.pesi.norma_maxima() <= (6.0_f64 / 8.0).sqrt());
+    }
+
+    /// Probat stratum ReLU novum pondera He et biases nullos accipere.
+    /// Teste qu’une nouvelle couche ReLU reçoit des poids He et des biais nuls.
+    #[test]
+    fn probatio_novus_relu() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(5);
+        let relu: StratumDensum = StratumDensum::novus_cum(8, 4, Activatio::Relu, &mut aleator);
+        assert!(relu.pesi.norma_maxima() <= (6.0_f64 / 8.0).sqrt() && relu.pesi.norma_maxima() > (6.0_f64 / 12.0).sqrt());
+        assert!(relu.bias.materia.iter().all(|&b| b == 0.0));
+    }
+
+    /// Probat probabilitatem omissionis extra [0, 1) in archivo strati reici.
+    /// Teste qu’une probabilité de dropout hors de [0, 1) est rejetée dans le fichier d’une couche.
+    #[test]
+    fn probatio_omissio_archivum() {
+        let mut json = serde_json::to_value(StratumDensum::<f64>::novus(2, 3, Activatio::Sigmoides)).unwrap();
+        json["omissio"] = serde_json::json!(1.5);
+        assert!(serde_json::from_value::<StratumDensum>(json.clone()).is_err());
+        json["omissio"] = serde_json::json!(0.5);
+        assert_eq!(serde_json::from_value::<StratumDensum>(json).unwrap().omissio, 0.5);
+    }
+}
@@ -1,7 +1,20 @@
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::fmt;
+use std::ops::{Index, IndexMut};
 use serde::{Serialize, Deserialize};
 
+mod decompositiones;
+mod error;
+mod instrumenta;
+mod multidimensionalis;
+mod numerus;
+mod operationes;
+pub use decompositiones::{DecompositioLU, DecompositioPropria, DecompositioQR};
+pub use error::ErrorTensoris;
+pub use multidimensionalis::{TensorND, TensorTND};
+pub use numerus::Numerus;
+
 //
 // TENSOR BIDIMENSIONALIS
 // ----------------------
@@ -11,10 +24,11 @@ use serde::{Serialize, Deserialize};
 /// Tensor bidimensionalis, cum ordinibus et columnis.
 /// Tenseur à deux dimensions, avec lignes et colonnes.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tensor2D {
-    /// Materia interna: rursus vectorum.
-/// Données internes : vecteur de vecteurs (matrice).
-    pub materia: Vec<Vec<f64>>,
+#[serde(try_from = "Forma2D<T>")]
+pub struct TensorT2D<T> {
+    /// Elementa contigua, ordo post ordinem (row-major).
+/// Données internes : tampon contigu, ligne après ligne.
+    pub elementa: Vec<T>,
 
     /// Numerus ordinum (rangs horizontalium).
 /// Nombre de lignes.
@@ -25,103 +39,383 @@ pub struct Tensor2D {
     pub columnae: usize,
 }
 
-impl Tensor2D {
-    /// Crea tensorem e vectore duorum dimensionum.
-/// Crée un tenseur à partir d’un vecteur 2D.
-    pub fn ex_vec(materia: Vec<Vec<f64>>) -> Self {
+/// Tensor bidimensionalis elementorum f64, typus praefinitus; `TensorT2D::<f32>` pro f32.
+/// Tenseur 2D à éléments f64, le type par défaut ; `TensorT2D::<f32>` pour f32.
+pub type Tensor2D = TensorT2D<f64>;
+
+/// Formae serializatae Tensor2D: elementa plana, vel `materia` nidificata archivorum veterum.
+/// Formes sérialisées d’un Tensor2D : éléments à plat, ou `materia` imbriquée des anciens fichiers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Forma2D<T> {
+    Plana { elementa: Vec<T>, ordines: usize, columnae: usize },
+    Nidificata { materia: Vec<Vec<T>>, ordines: usize, columnae: usize },
+}
+
+// Deserializatio per constructores verificantes: archivum truncatum errorem reddit, non panicum posterius.
+// La désérialisation passe par les constructeurs vérifiés : un fichier tronqué donne une erreur, pas une panique plus tard.
+impl<T> TryFrom<Forma2D<T>> for TensorT2D<T> {
+    type Error = ErrorTensoris;
+
+    fn try_from(forma: Forma2D<T>) -> Result<Self, ErrorTensoris> {
+        match forma {
+            Forma2D::Plana { elementa, ordines, columnae } => Self::try_ex_elementis(ordines, columnae, elementa),
+            Forma2D::Nidificata { materia, ordines, columnae } => {
+                let tensor = Self::try_ex_vec(materia)?;
+                if (tensor.ordines, tensor.columnae) != (ordines, columnae) {
+                    return Err(ErrorTensoris::FormaeDiscrepantes {
+                        operatio: "Tensor2D::deserializatio",
+                        exspectata: vec![ordines, columnae],
+                        reperta: vec![tensor.ordines, tensor.columnae],
+                    });
+                }
+                Ok(tensor)
+            }
+        }
+    }
+}
+
+// Constructores formam verificantes, sine vinculo `Numerus`, ut deserializatio quoque eos adhibeat.
+// Constructeurs vérifiant la forme, sans contrainte `Numerus`, pour que la désérialisation les utilise aussi.
+impl<T> TensorT2D<T> {
+    /// Crea tensorem e vectore duorum dimensionum, errorem reddens si ordines inaequales sunt.
+/// Crée un tenseur depuis un vecteur 2D ; renvoie une erreur si les lignes sont irrégulières.
+    pub fn try_ex_vec(materia: Vec<Vec<T>>) -> Result<Self, ErrorTensoris> {
         let ordines = materia.len();
 
         // Saltem unus ordo necessarius est.
         // Il doit y avoir au moins une ligne.
-        assert!(ordines > 0, "Saltem unus ordo esse debet.");
+        if ordines == 0 {
+            return Err(ErrorTensoris::MateriaVacua { operatio: "Tensor2D::ex_vec" });
+        }
 
         let columnae = materia[0].len();
 
         // Omnes ordines aequae longitudinis esse debent.
         // Toutes les lignes doivent avoir la même longueur.
-        for ordo in &materia {
-            assert_eq!(ordo.len(), columnae, "Omnes ordines eandem longitudinem habere debent.");
+        for (index, ordo) in materia.iter().enumerate() {
+            if ordo.len() != columnae {
+                return Err(ErrorTensoris::OrdoInaequalis {
+                    operatio: "Tensor2D::ex_vec",
+                    index: vec![index],
+                    exspectata: columnae,
+                    reperta: ordo.len(),
+                });
+            }
+        }
+
+        Ok(Self { elementa: materia.into_iter().flatten().collect(), ordines, columnae })
+    }
+
+    /// Crea tensorem ex elementis contiguis, errorem reddens si numerus elementorum formae non convenit.
+/// Crée un tenseur depuis un tampon contigu ; erreur si le nombre d’éléments ne correspond pas.
+    pub fn try_ex_elementis(ordines: usize, columnae: usize, elementa: Vec<T>) -> Result<Self, ErrorTensoris> {
+        if elementa.len() != ordines * columnae {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor2D::ex_elementis",
+                exspectata: vec![ordines * columnae],
+                reperta: vec![elementa.len()],
+            });
         }
+        Ok(Self { elementa, ordines, columnae })
+    }
+}
+
+impl<T: Numerus> TensorT2D<T> {
+    /// Crea tensorem e vectore duorum dimensionum.
+/// Crée un tenseur à partir d’un vecteur 2D.
+    pub fn ex_vec(materia: Vec<Vec<T>>) -> Self {
+        Self::try_ex_vec(materia).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        Self { materia, ordines, columnae }
+    /// Crea tensorem ex elementis contiguis (ordo post ordinem).
+/// Crée un tenseur depuis un tampon contigu (ligne après ligne).
+    pub fn ex_elementis(ordines: usize, columnae: usize, elementa: Vec<T>) -> Self {
+        Self::try_ex_elementis(ordines, columnae, elementa).unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Tensor plene nullus.
 /// Crée une matrice remplie de zéros.
     pub fn nullus(ordines: usize, columnae: usize) -> Self {
-        let materia = vec![vec![0.0; columnae]; ordines];
-        Self { materia, ordines, columnae }
+        let elementa = vec![T::NULLUS; ordines * columnae];
+        Self { elementa, ordines, columnae }
     }
 
     /// Tensor fortuitus inter 0 et 1.
 /// Crée une matrice avec des valeurs aléatoires uniformes.
     pub fn fortuitus(ordines: usize, columnae: usize) -> Self {
-        let mut aleator = rand::thread_rng();
+        Self::fortuitus_cum(ordines, columnae, &mut rand::rng())
+    }
 
+    /// Tensor fortuitus ex generatore dato; idem status generatoris eadem elementa reddit.
+/// Matrice aléatoire tirée du générateur donné ; un même état donne les mêmes éléments.
+    pub fn fortuitus_cum<R: Rng + ?Sized>(ordines: usize, columnae: usize, aleator: &mut R) -> Self {
         // Genera matricem aleatoriam.
         // Génère une matrice avec des flottants aléatoires.
-        let materia = (0..ordines)
-            .map(|_| (0..columnae).map(|_| aleator.alea::<f64>()).collect())
+        let elementa = (0..ordines * columnae).map(|_| T::ex_f64(aleator.random::<f64>())).collect();
+
+        Self { elementa, ordines, columnae }
+    }
+
+    /// Tensor fortuitus reproducibilis ex semine.
+/// Matrice aléatoire reproductible à partir d’une graine.
+    pub fn fortuitus_ex_semine(ordines: usize, columnae: usize, semen: u64) -> Self {
+        Self::fortuitus_cum(ordines, columnae, &mut ChaCha8Rng::seed_from_u64(semen))
+    }
+
+    /// Tensor valorum uniformium in [inferior, superior) ex generatore dato.
+/// Matrice de valeurs uniformes dans [inférieur, supérieur) tirées du générateur donné.
+    pub fn uniformis_cum<R: Rng + ?Sized>(ordines: usize, columnae: usize, inferior: f64, superior: f64, aleator: &mut R) -> Self {
+        let elementa = (0..ordines * columnae)
+            .map(|_| T::ex_f64(inferior + (superior - inferior) * aleator.random::<f64>()))
+            .collect();
+        Self { elementa, ordines, columnae }
+    }
+
+    /// Tensor valorum normalium N(media, deviatio²).
+/// Matrice de valeurs normales N(moyenne, écart-type²).
+    pub fn normalis(ordines: usize, columnae: usize, media: f64, deviatio: f64) -> Self {
+        Self::normalis_cum(ordines, columnae, media, deviatio, &mut rand::rng())
+    }
+
+    /// Tensor valorum normalium ex generatore dato.
+/// Matrice de valeurs normales tirées du générateur donné.
+    pub fn normalis_cum<R: Rng + ?Sized>(ordines: usize, columnae: usize, media: f64, deviatio: f64, aleator: &mut R) -> Self {
+        let elementa = (0..ordines * columnae)
+            .map(|_| T::ex_f64(media + deviatio * normalis_canonicus(aleator)))
             .collect();
+        Self { elementa, ordines, columnae }
+    }
 
-        Self { materia, ordines, columnae }
+    /// Tensor valorum normalium truncatorum: valores ultra duas deviationes iterum trahuntur.
+/// Matrice normale tronquée : les valeurs au-delà de deux écarts-types sont retirées.
+    pub fn normalis_truncata(ordines: usize, columnae: usize, media: f64, deviatio: f64) -> Self {
+        Self::normalis_truncata_cum(ordines, columnae, media, deviatio, &mut rand::rng())
+    }
+
+    /// Tensor valorum normalium truncatorum ex generatore dato.
+/// Matrice normale tronquée tirée du générateur donné.
+    pub fn normalis_truncata_cum<R: Rng + ?Sized>(ordines: usize, columnae: usize, media: f64, deviatio: f64, aleator: &mut R) -> Self {
+        let elementa = (0..ordines * columnae)
+            .map(|_| loop {
+                let z = normalis_canonicus(aleator);
+                if z.abs() <= 2.0 {
+                    break T::ex_f64(media + deviatio * z);
+                }
+            })
+            .collect();
+        Self { elementa, ordines, columnae }
+    }
+
+    /// Forma tensoris: [ordines, columnae].
+/// Forme du tenseur : [lignes, colonnes].
+    pub fn forma(&self) -> [usize; 2] {
+        [self.ordines, self.columnae]
+    }
+
+    /// Gradus (strides): saltus in elementis per ordinem et per columnam.
+/// Pas (strides) : décalage dans le tampon par ligne et par colonne.
+    pub fn gradus(&self) -> [usize; 2] {
+        [self.columnae, 1]
+    }
+
+    /// Converte elementa in alium typum numericum (e.g. f64 → f32).
+/// Convertit les éléments vers un autre type numérique (p. ex. f64 → f32).
+    pub fn converte<U: Numerus>(&self) -> TensorT2D<U> {
+        TensorT2D::ex_elementis(self.ordines, self.columnae, self.elementa.iter().map(|x| x.converte()).collect())
+    }
+
+    /// Materia ut vector vectorum (pro compatibilitate; copia fit).
+/// Données sous forme de vecteur de vecteurs (compatibilité ; effectue une copie).
+    pub fn materia(&self) -> Vec<Vec<T>> {
+        self.elementa.chunks(self.columnae.max(1)).map(|ordo| ordo.to_vec()).collect()
+    }
+
+    /// Ordo `i` ut segmentum contiguum.
+/// Ligne `i` sous forme de tranche contiguë.
+    pub fn ordo(&self, i: usize) -> &[T] {
+        &self.elementa[i * self.columnae..(i + 1) * self.columnae]
+    }
+
+    /// Ordo `i` mutabilis.
+/// Ligne `i` modifiable.
+    pub fn ordo_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.elementa[i * self.columnae..(i + 1) * self.columnae]
+    }
+
+    /// Iterator super ordines.
+/// Itérateur sur les lignes.
+    pub fn ordines_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.elementa.chunks(self.columnae.max(1)).take(self.ordines)
     }
 
     /// Adde alium tensorem (dimensiones aequae).
 /// Additionne deux tenseurs de même dimension.
     pub fn adde(&self, alius: &Self) -> Self {
-        assert_eq!(self.ordines, alius.ordines);
-        assert_eq!(self.columnae, alius.columnae);
+        self.try_adde(alius).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Adde alium tensorem, errorem reddens si formae differunt.
+/// Additionne deux tenseurs ; renvoie une erreur si les formes diffèrent.
+    pub fn try_adde(&self, alius: &Self) -> Result<Self, ErrorTensoris> {
+        if self.forma() != alius.forma() {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor2D::adde",
+                exspectata: self.forma().to_vec(),
+                reperta: alius.forma().to_vec(),
+            });
+        }
 
         // Summatio elementa ad elementum.
         // Addition élément par élément.
-        let materia = self.materia.iter().zip(&alius.materia)
-            .map(|(ordo_a, ordo_b)| {
-                ordo_a.iter().zip(ordo_b).map(|(a, b)| a + b).collect()
-            })
-            .collect();
+        let elementa = self.elementa.iter().zip(&alius.elementa).map(|(&a, &b)| a + b).collect();
 
-        Self::ex_vec(materia)
+        Ok(Self { elementa, ordines: self.ordines, columnae: self.columnae })
     }
 
     /// Multiplica omnem elementum per scalar.
 /// Multiplie tous les éléments par un scalaire.
-    pub fn multiplica_per_scalar(&self, scala: f64) -> Self {
-        let materia = self.materia.iter()
-            .map(|ordo| ordo.iter().map(|x| x * scala).collect())
-            .collect();
-
-        Self::ex_vec(materia)
+    pub fn multiplica_per_scalar(&self, scala: T) -> Self {
+        let elementa = self.elementa.iter().map(|&x| x * scala).collect();
+        Self { elementa, ordines: self.ordines, columnae: self.columnae }
     }
 
-    /// Productum matriciale duorum tensorum.
-/// Produit matriciel entre deux matrices (dot product).
+    /// Productum matriciale duorum tensorum (per tegulas, ordine i-k-j).
+/// Produit matriciel entre deux matrices, par blocs et dans l’ordre i-k-j.
     pub fn productum_matriciale(&self, alius: &Self) -> Self {
+        self.try_productum_matriciale(alius).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Productum matriciale, errorem reddens si columnae primi ordinibus secundi non aequantur.
+/// Produit matriciel ; renvoie une erreur si les dimensions internes diffèrent.
+    pub fn try_productum_matriciale(&self, alius: &Self) -> Result<Self, ErrorTensoris> {
         // Verifica compatibilitatem dimensionum.
         // Vérifie la compatibilité des dimensions.
+        if self.columnae != alius.ordines {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor2D::productum_matriciale",
+                exspectata: vec![self.columnae, alius.columnae],
+                reperta: alius.forma().to_vec(),
+            });
+        }
+
+        let mut resultatum = Self::nullus(self.ordines, alius.columnae);
+        let (n, p) = (self.columnae, alius.columnae);
+
+        #[cfg(feature = "parallel")]
+        {
+            // Ordines resultati inter fila dividuntur; quodque filum tegulas suas computat.
+            // Les lignes du résultat sont réparties entre les fils d’exécution.
+            let fila = numerus_filorum(self.ordines * n * p);
+            if fila > 1 {
+                let ordines_per_filum = self.ordines.div_ceil(fila);
+                std::thread::scope(|ambitus| {
+                    for (index, pars) in resultatum.elementa.chunks_mut(ordines_per_filum * p).enumerate() {
+                        let initium = index * ordines_per_filum * n;
+                        let a = &self.elementa[initium..initium + pars.len() / p.max(1) * n];
+                        let b = &alius.elementa;
+                        ambitus.spawn(move || productum_tegulatum(a, b, pars, n, p));
+                    }
+                });
+                return Ok(resultatum);
+            }
+        }
+
+        productum_tegulatum(&self.elementa, &alius.elementa, &mut resultatum.elementa, n, p);
+        Ok(resultatum)
+    }
+
+    /// Productum matriciale simplex per triplicem ansam i-j-k (ad comparationem et probationes).
+/// Produit matriciel naïf par triple boucle i-j-k (pour comparaison et tests).
+    pub fn productum_matriciale_simplex(&self, alius: &Self) -> Self {
         assert_eq!(self.columnae, alius.ordines);
 
-        let mut resultatum = vec![vec![0.0; alius.columnae]; self.ordines];
+        let mut resultatum = Self::nullus(self.ordines, alius.columnae);
 
         // Computatio elementorum via summatio multiplicatorum.
         // Calcul classique par triple boucle.
         for i in 0..self.ordines {
             for j in 0..alius.columnae {
+                let mut summa = T::NULLUS;
                 for k in 0..self.columnae {
-                    resultatum[i][j] += self.materia[i][k] * alius.materia[k][j];
+                    summa += self.elementa[i * self.columnae + k] * alius.elementa[k * alius.columnae + j];
                 }
+                resultatum.elementa[i * alius.columnae + j] = summa;
             }
         }
 
-        Self::ex_vec(resultatum)
+        resultatum
+    }
+}
+
+/// Latitudo tegulae (in elementis) pro productu matriciali.
+/// Taille des blocs (en éléments) pour le produit matriciel.
+const TEGULA: usize = 64;
+
+/// Nucleus productus per tegulas: c += a·b, ubi a [m x n], b [n x p], c [m x p], omnia ordine ordinum.
+/// Noyau par blocs : c += a·b ; l’ordre i-k-j parcourt b et c de façon contiguë.
+fn productum_tegulatum<T: Numerus>(a: &[T], b: &[T], c: &mut [T], n: usize, p: usize) {
+    if n == 0 || p == 0 {
+        return;
+    }
+    let m = a.len() / n;
+
+    for ii in (0..m).step_by(TEGULA) {
+        let i_finis = (ii + TEGULA).min(m);
+        for kk in (0..n).step_by(TEGULA) {
+            let k_finis = (kk + TEGULA).min(n);
+            for jj in (0..p).step_by(TEGULA) {
+                let j_finis = (jj + TEGULA).min(p);
+
+                for i in ii..i_finis {
+                    let ordo_c = &mut c[i * p + jj..i * p + j_finis];
+                    for k in kk..k_finis {
+                        let a_ik = a[i * n + k];
+                        let ordo_b = &b[k * p + jj..k * p + j_finis];
+                        for (c_ij, b_kj) in ordo_c.iter_mut().zip(ordo_b) {
+                            *c_ij += a_ik * *b_kj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Numerus filorum pro productu tantae magnitudinis; parva producta in uno filo manent.
+/// Nombre de fils pour un produit de cette taille ; les petits produits restent séquentiels.
+#[cfg(feature = "parallel")]
+fn numerus_filorum(operationes: usize) -> usize {
+    const LIMES: usize = 64 * 64 * 64;
+    if operationes < LIMES {
+        return 1;
+    }
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Accessus ad elementum per (ordo, columna).
+/// Accès à un élément par (ligne, colonne).
+impl<T: Numerus> Index<(usize, usize)> for TensorT2D<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.ordines && j < self.columnae, "Index extra formam tensoris.");
+        &self.elementa[i * self.columnae + j]
+    }
+}
+
+impl<T: Numerus> IndexMut<(usize, usize)> for TensorT2D<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.ordines && j < self.columnae, "Index extra formam tensoris.");
+        &mut self.elementa[i * self.columnae + j]
     }
 }
 
 /// Forma humanis legibilis tensoris.
 /// Affichage lisible pour l'œil humain.
-impl fmt::Display for Tensor2D {
+impl<T: Numerus> fmt::Display for TensorT2D<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for ordo in &self.materia {
+        for ordo in self.ordines_iter() {
             writeln!(
                 f,
                 "{}",
@@ -144,20 +438,48 @@ impl fmt::Display for Tensor2D {
 /// Tensor unidimensionalis, sive vector.
 /// Vecteur simple, une seule dimension.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tensor1D {
+#[serde(try_from = "Forma1D<T>")]
+pub struct TensorT1D<T> {
     /// Elementa vectoris.
 /// Les valeurs du vecteur.
-    pub materia: Vec<f64>,
+    pub materia: Vec<T>,
 
     /// Longitudo vectoris.
 /// Taille du vecteur.
     pub magnitudo: usize,
 }
 
-impl Tensor1D {
+/// Vector elementorum f64, typus praefinitus; `TensorT1D::<f32>` pro f32.
+/// Vecteur à éléments f64, le type par défaut ; `TensorT1D::<f32>` pour f32.
+pub type Tensor1D = TensorT1D<f64>;
+
+/// Forma serializata Tensor1D, ante verificationem longitudinis.
+/// Forme sérialisée d’un Tensor1D, avant vérification de la longueur.
+#[derive(Deserialize)]
+struct Forma1D<T> {
+    materia: Vec<T>,
+    magnitudo: usize,
+}
+
+impl<T> TryFrom<Forma1D<T>> for TensorT1D<T> {
+    type Error = ErrorTensoris;
+
+    fn try_from(Forma1D { materia, magnitudo }: Forma1D<T>) -> Result<Self, ErrorTensoris> {
+        if materia.len() != magnitudo {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor1D::deserializatio",
+                exspectata: vec![magnitudo],
+                reperta: vec![materia.len()],
+            });
+        }
+        Ok(Self { materia, magnitudo })
+    }
+}
+
+impl<T: Numerus> TensorT1D<T> {
     /// Ex vectore floatium.
-/// Crée un vecteur à partir d'un `Vec<f64>`.
-    pub fn ex_vec(materia: Vec<f64>) -> Self {
+/// Crée un vecteur à partir d'un `Vec<T>`.
+    pub fn ex_vec(materia: Vec<T>) -> Self {
         let magnitudo = materia.len();
         Self { materia, magnitudo }
     }
@@ -165,46 +487,93 @@ impl Tensor1D {
     /// Vector nullus (omnes elementa sunt 0).
 /// Vecteur rempli de zéros.
     pub fn nullus(magnitudo: usize) -> Self {
-        let materia = vec![0.0; magnitudo];
+        let materia = vec![T::NULLUS; magnitudo];
         Self { materia, magnitudo }
     }
 
     /// Vector fortuitus.
 /// Vecteur de valeurs aléatoires entre 0 et 1.
     pub fn fortuitus(magnitudo: usize) -> Self {
-        let mut aleator = rand::thread_rng();
-        let materia = (0..magnitudo).map(|_| aleator.alea::<f64>()).collect();
+        Self::fortuitus_cum(magnitudo, &mut rand::rng())
+    }
+
+    /// Vector fortuitus ex generatore dato.
+/// Vecteur aléatoire tiré du générateur donné.
+    pub fn fortuitus_cum<R: Rng + ?Sized>(magnitudo: usize, aleator: &mut R) -> Self {
+        let materia = (0..magnitudo).map(|_| T::ex_f64(aleator.random::<f64>())).collect();
         Self { materia, magnitudo }
     }
 
+    /// Vector fortuitus reproducibilis ex semine.
+/// Vecteur aléatoire reproductible à partir d’une graine.
+    pub fn fortuitus_ex_semine(magnitudo: usize, semen: u64) -> Self {
+        Self::fortuitus_cum(magnitudo, &mut ChaCha8Rng::seed_from_u64(semen))
+    }
+
+    /// Converte elementa in alium typum numericum.
+/// Convertit les éléments vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> TensorT1D<U> {
+        TensorT1D::ex_vec(self.materia.iter().map(|x| x.converte()).collect())
+    }
+
     /// Adde alium vectorem.
 /// Additionne deux vecteurs.
     pub fn adde(&self, alius: &Self) -> Self {
-        assert_eq!(self.magnitudo, alius.magnitudo);
+        self.try_adde(alius).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Adde alium vectorem, errorem reddens si magnitudines differunt.
+/// Additionne deux vecteurs ; renvoie une erreur si les tailles diffèrent.
+    pub fn try_adde(&self, alius: &Self) -> Result<Self, ErrorTensoris> {
+        self.verifica_magnitudinem("Tensor1D::adde", alius)?;
         let materia = self.materia.iter().zip(&alius.materia)
-            .map(|(a, b)| a + b).collect();
-        Self::ex_vec(materia)
+            .map(|(&a, &b)| a + b).collect();
+        Ok(Self::ex_vec(materia))
     }
 
     /// Multiplica elementa per scalar.
 /// Multiplie chaque valeur par un scalaire.
-    pub fn multiplica_per_scalar(&self, scala: f64) -> Self {
-        let materia = self.materia.iter().map(|x| x * scala).collect();
+    pub fn multiplica_per_scalar(&self, scala: T) -> Self {
+        let materia = self.materia.iter().map(|&x| x * scala).collect();
         Self::ex_vec(materia)
     }
 
+    /// Scala vectorem per factorem (idem ac `multiplica_per_scalar`).
+/// Met le vecteur à l’échelle (identique à `multiplica_per_scalar`).
+    pub fn scale(&self, factor: T) -> Self {
+        self.multiplica_per_scalar(factor)
+    }
+
     /// Productum scalaris (dot product).
 /// Produit scalaire entre deux vecteurs.
-    pub fn productum_scalaris(&self, alius: &Self) -> f64 {
-        assert_eq!(self.magnitudo, alius.magnitudo);
-        self.materia.iter().zip(&alius.materia).map(|(a, b)| a * b).sum()
+    pub fn productum_scalaris(&self, alius: &Self) -> T {
+        self.try_productum_scalaris(alius).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Productum scalaris, errorem reddens si magnitudines differunt.
+/// Produit scalaire ; renvoie une erreur si les tailles diffèrent.
+    pub fn try_productum_scalaris(&self, alius: &Self) -> Result<T, ErrorTensoris> {
+        self.verifica_magnitudinem("Tensor1D::productum_scalaris", alius)?;
+        Ok(self.materia.iter().zip(&alius.materia).map(|(&a, &b)| a * b).sum())
+    }
+
+    /// Verifica alium vectorem eandem magnitudinem habere.
+/// Vérifie que l’autre vecteur a la même taille.
+    fn verifica_magnitudinem(&self, operatio: &'static str, alius: &Self) -> Result<(), ErrorTensoris> {
+        if self.magnitudo != alius.magnitudo {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio,
+                exspectata: vec![self.magnitudo],
+                reperta: vec![alius.magnitudo],
+            });
+        }
+        Ok(())
     }
 
     /// Transpone in columnam (Tensor2D).
 /// Transforme ce vecteur en matrice colonne.
-    pub fn transpone(&self) -> Tensor2D {
-        let materia = self.materia.iter().map(|x| vec![*x]).collect();
-        Tensor2D::ex_vec(materia)
+    pub fn transpone(&self) -> TensorT2D<T> {
+        TensorT2D::ex_elementis(self.magnitudo, 1, self.materia.clone())
     }
 }
 
@@ -217,10 +586,11 @@ impl Tensor1D {
 /// Tensor tridimensionalis: profunditas × altitudo × latitudo.
 /// Tenseur à trois dimensions : profondeur × hauteur × largeur.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tensor3D {
-    /// Materia interna: series matricum.
-/// Données internes : série de matrices (vecteur de vecteurs de vecteurs).
-    pub materia: Vec<Vec<Vec<f64>>>,
+#[serde(try_from = "Forma3D<T>")]
+pub struct TensorT3D<T> {
+    /// Elementa contigua: planum post planum, ordo post ordinem.
+/// Données internes : tampon contigu, plan après plan, ligne après ligne.
+    pub elementa: Vec<T>,
 
     /// Numerus planorum seu stratorum.
 /// Nombre de plans/profondeur.
@@ -235,54 +605,215 @@ pub struct Tensor3D {
     pub latitudo: usize,
 }
 
-impl Tensor3D {
-    /// Crea tensorem e materia explicita.
-/// Crée un tenseur 3D à partir de données explicites.
-    pub fn ex_vec(materia: Vec<Vec<Vec<f64>>>) -> Self {
+/// Tensor tridimensionalis elementorum f64, typus praefinitus; `TensorT3D::<f32>` pro f32.
+/// Tenseur 3D à éléments f64, le type par défaut ; `TensorT3D::<f32>` pour f32.
+pub type Tensor3D = TensorT3D<f64>;
+
+/// Formae serializatae Tensor3D: elementa plana, vel `materia` nidificata archivorum veterum.
+/// Formes sérialisées d’un Tensor3D : éléments à plat, ou `materia` imbriquée des anciens fichiers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Forma3D<T> {
+    Plana { elementa: Vec<T>, profunditas: usize, altitudo: usize, latitudo: usize },
+    Nidificata { materia: Vec<Vec<Vec<T>>>, profunditas: usize, altitudo: usize, latitudo: usize },
+}
+
+impl<T> TryFrom<Forma3D<T>> for TensorT3D<T> {
+    type Error = ErrorTensoris;
+
+    fn try_from(forma: Forma3D<T>) -> Result<Self, ErrorTensoris> {
+        match forma {
+            Forma3D::Plana { elementa, profunditas, altitudo, latitudo } => {
+                Self::try_ex_elementis(profunditas, altitudo, latitudo, elementa)
+            }
+            Forma3D::Nidificata { materia, profunditas, altitudo, latitudo } => {
+                let tensor = Self::try_ex_vec(materia)?;
+                let reperta = vec![tensor.profunditas, tensor.altitudo, tensor.latitudo];
+                if reperta != [profunditas, altitudo, latitudo] {
+                    return Err(ErrorTensoris::FormaeDiscrepantes {
+                        operatio: "Tensor3D::deserializatio",
+                        exspectata: vec![profunditas, altitudo, latitudo],
+                        reperta,
+                    });
+                }
+                Ok(tensor)
+            }
+        }
+    }
+}
+
+// Constructores formam verificantes, sine vinculo `Numerus`, ut deserializatio quoque eos adhibeat.
+// Constructeurs vérifiant la forme, sans contrainte `Numerus`, pour que la désérialisation les utilise aussi.
+impl<T> TensorT3D<T> {
+    /// Crea tensorem e materia explicita, errorem reddens si strata vel ordines inaequales sunt.
+/// Crée un tenseur 3D ; renvoie une erreur si les plans ou les lignes sont irréguliers.
+    pub fn try_ex_vec(materia: Vec<Vec<Vec<T>>>) -> Result<Self, ErrorTensoris> {
+        let operatio = "Tensor3D::ex_vec";
         let profunditas = materia.len();
-        assert!(profunditas > 0, "Saltem unum stratum esse debet."); // Il doit y avoir au moins un plan
+
+        // Il doit y avoir au moins un plan et une ligne.
+        if profunditas == 0 || materia[0].is_empty() {
+            return Err(ErrorTensoris::MateriaVacua { operatio });
+        }
 
         let altitudo = materia[0].len();
         let latitudo = materia[0][0].len();
 
-        for planum in &materia {
-            assert_eq!(planum.len(), altitudo, "Omnia strata eandem altitudinem habere debent.");
-            for ordo in planum {
-                assert_eq!(ordo.len(), latitudo, "Omnes ordines eandem latitudinem habere debent.");
+        for (k, planum) in materia.iter().enumerate() {
+            if planum.len() != altitudo {
+                return Err(ErrorTensoris::OrdoInaequalis {
+                    operatio,
+                    index: vec![k],
+                    exspectata: altitudo,
+                    reperta: planum.len(),
+                });
+            }
+            for (i, ordo) in planum.iter().enumerate() {
+                if ordo.len() != latitudo {
+                    return Err(ErrorTensoris::OrdoInaequalis {
+                        operatio,
+                        index: vec![k, i],
+                        exspectata: latitudo,
+                        reperta: ordo.len(),
+                    });
+                }
             }
         }
 
-        Self { materia, profunditas, altitudo, latitudo }
+        let elementa = materia.into_iter().flatten().flatten().collect();
+        Ok(Self { elementa, profunditas, altitudo, latitudo })
+    }
+
+    /// Crea tensorem ex elementis contiguis, errorem reddens si numerus elementorum formae non convenit.
+/// Crée un tenseur 3D depuis un tampon contigu ; erreur si le nombre d’éléments ne correspond pas.
+    pub fn try_ex_elementis(
+        profunditas: usize,
+        altitudo: usize,
+        latitudo: usize,
+        elementa: Vec<T>,
+    ) -> Result<Self, ErrorTensoris> {
+        if elementa.len() != profunditas * altitudo * latitudo {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor3D::ex_elementis",
+                exspectata: vec![profunditas * altitudo * latitudo],
+                reperta: vec![elementa.len()],
+            });
+        }
+        Ok(Self { elementa, profunditas, altitudo, latitudo })
+    }
+}
+
+impl<T: Numerus> TensorT3D<T> {
+    /// Crea tensorem e materia explicita.
+/// Crée un tenseur 3D à partir de données explicites.
+    pub fn ex_vec(materia: Vec<Vec<Vec<T>>>) -> Self {
+        Self::try_ex_vec(materia).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Crea tensorem ex elementis contiguis.
+/// Crée un tenseur 3D depuis un tampon contigu.
+    pub fn ex_elementis(profunditas: usize, altitudo: usize, latitudo: usize, elementa: Vec<T>) -> Self {
+        Self::try_ex_elementis(profunditas, altitudo, latitudo, elementa).unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Tensor nullus tridimensionalis.
 /// Crée un tenseur 3D rempli de zéros.
     pub fn nullus(profunditas: usize, altitudo: usize, latitudo: usize) -> Self {
-        let materia = vec![vec![vec![0.0; latitudo]; altitudo]; profunditas];
-        Self { materia, profunditas, altitudo, latitudo }
+        let elementa = vec![T::NULLUS; profunditas * altitudo * latitudo];
+        Self { elementa, profunditas, altitudo, latitudo }
     }
 
     /// Tensor fortuitus tridimensionalis.
 /// Crée un tenseur 3D avec valeurs aléatoires entre 0 et 1.
     pub fn fortuitus(profunditas: usize, altitudo: usize, latitudo: usize) -> Self {
-        let mut aleator = rand::thread_rng();
-        let materia = (0..profunditas).map(|_| {
-            (0..altitudo).map(|_| {
-                (0..latitudo).map(|_| aleator.alea::<f64>()).collect()
-            }).collect()
-        }).collect();
+        Self::fortuitus_cum(profunditas, altitudo, latitudo, &mut rand::rng())
+    }
+
+    /// Tensor tridimensionalis fortuitus ex generatore dato.
+/// Tenseur 3D aléatoire tiré du générateur donné.
+    pub fn fortuitus_cum<R: Rng + ?Sized>(profunditas: usize, altitudo: usize, latitudo: usize, aleator: &mut R) -> Self {
+        let elementa = (0..profunditas * altitudo * latitudo)
+            .map(|_| T::ex_f64(aleator.random::<f64>()))
+            .collect();
+
+        Self { elementa, profunditas, altitudo, latitudo }
+    }
+
+    /// Tensor tridimensionalis fortuitus reproducibilis ex semine.
+/// Tenseur 3D aléatoire reproductible à partir d’une graine.
+    pub fn fortuitus_ex_semine(profunditas: usize, altitudo: usize, latitudo: usize, semen: u64) -> Self {
+        Self::fortuitus_cum(profunditas, altitudo, latitudo, &mut ChaCha8Rng::seed_from_u64(semen))
+    }
+
+    /// Forma tensoris: [profunditas, altitudo, latitudo].
+/// Forme du tenseur : [profondeur, hauteur, largeur].
+    pub fn forma(&self) -> [usize; 3] {
+        [self.profunditas, self.altitudo, self.latitudo]
+    }
+
+    /// Gradus (strides) per planum, ordinem et columnam.
+/// Pas (strides) par plan, par ligne et par colonne.
+    pub fn gradus(&self) -> [usize; 3] {
+        [self.altitudo * self.latitudo, self.latitudo, 1]
+    }
+
+    /// Converte elementa in alium typum numericum.
+/// Convertit les éléments vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> TensorT3D<U> {
+        let elementa = self.elementa.iter().map(|x| x.converte()).collect();
+        TensorT3D::ex_elementis(self.profunditas, self.altitudo, self.latitudo, elementa)
+    }
+
+    /// Materia ut vector triplex (pro compatibilitate; copia fit).
+/// Données sous forme de vecteur triple (compatibilité ; effectue une copie).
+    pub fn materia(&self) -> Vec<Vec<Vec<T>>> {
+        (0..self.profunditas).map(|k| self.planum(k).materia()).collect()
+    }
+
+    /// Planum `k` ut Tensor2D (copia).
+/// Plan `k` sous forme de Tensor2D (copie).
+    pub fn planum(&self, k: usize) -> TensorT2D<T> {
+        let magnitudo = self.altitudo * self.latitudo;
+        TensorT2D::ex_elementis(
+            self.altitudo,
+            self.latitudo,
+            self.elementa[k * magnitudo..(k + 1) * magnitudo].to_vec(),
+        )
+    }
+
+    /// Ordo `i` plani `k` ut segmentum contiguum.
+/// Ligne `i` du plan `k` sous forme de tranche contiguë.
+    pub fn ordo(&self, k: usize, i: usize) -> &[T] {
+        let initium = k * self.altitudo * self.latitudo + i * self.latitudo;
+        &self.elementa[initium..initium + self.latitudo]
+    }
+}
+
+/// Accessus ad elementum per (planum, ordo, columna).
+/// Accès à un élément par (plan, ligne, colonne).
+impl<T: Numerus> Index<(usize, usize, usize)> for TensorT3D<T> {
+    type Output = T;
 
-        Self { materia, profunditas, altitudo, latitudo }
+    fn index(&self, (k, i, j): (usize, usize, usize)) -> &T {
+        assert!(k < self.profunditas && i < self.altitudo && j < self.latitudo, "Index extra formam tensoris.");
+        &self.elementa[(k * self.altitudo + i) * self.latitudo + j]
+    }
+}
+
+impl<T: Numerus> IndexMut<(usize, usize, usize)> for TensorT3D<T> {
+    fn index_mut(&mut self, (k, i, j): (usize, usize, usize)) -> &mut T {
+        assert!(k < self.profunditas && i < self.altitudo && j < self.latitudo, "Index extra formam tensoris.");
+        &mut self.elementa[(k * self.altitudo + i) * self.latitudo + j]
     }
 }
 
 /// Forma legibilis tensoris tridimensionalis.
 /// Affichage lisible d’un tenseur 3D.
-impl fmt::Display for Tensor3D {
+impl<T: Numerus> fmt::Display for TensorT3D<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (i, planum) in self.materia.iter().enumerate() {
+        for i in 0..self.profunditas {
             writeln!(f, "Stratum {}:", i)?;
-            for ordo in planum {
+            for ordo in (0..self.altitudo).map(|j| self.ordo(i, j)) {
                 writeln!(
                     f,
                     "{}",
@@ -292,9 +823,104 @@ impl fmt::Display for Tensor3D {
                         .join("\t")
                 )?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
         Ok(())
     }
 }
 
+/// Valor normalis canonicus N(0, 1) per transformationem Box–Muller.
+/// Valeur normale centrée réduite N(0, 1) par la transformation de Box–Muller.
+fn normalis_canonicus<R: Rng + ?Sized>(aleator: &mut R) -> f64 {
+    // 1 − u in (0, 1], ne logarithmus nullius fiat.
+    // 1 − u est dans (0, 1], pour éviter le logarithme de zéro.
+    let u1 = 1.0 - aleator.random::<f64>();
+    let u2 = aleator.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+
+    /// Probat momenta empirica distributionis normalis.
+    /// Teste les moments empiriques de la loi normale.
+    #[test]
+    fn probatio_normalis_momenta() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        let normalis = Tensor2D::normalis_cum(200, 200, 1.0, 0.5, &mut aleator);
+        let varianta = normalis.map(|x| (x - 1.0).powi(2)).media();
+        assert!((normalis.media() - 1.0).abs() < 0.01);
+        assert!((varianta - 0.25).abs() < 0.01);
+    }
+
+    /// Probat distributionem normalem truncatam intra ±2σ manere.
+    /// Teste que la loi normale tronquée reste dans ±2σ.
+    #[test]
+    fn probatio_normalis_truncata() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(17);
+        let truncata = Tensor2D::normalis_truncata_cum(50, 50, 0.0, 0.5, &mut aleator);
+        assert!(truncata.norma_maxima() <= 1.0);
+    }
+
+    /// Probat idem semen eundem tensorem f32 reddere.
+    /// Teste qu’une même graine donne le même tenseur f32.
+    #[test]
+    fn probatio_semen_f32() {
+        assert_eq!(TensorT2D::<f32>::fortuitus_ex_semine(2, 3, 1), TensorT2D::fortuitus_ex_semine(2, 3, 1));
+    }
+
+    /// Probat valores seminis fixos manere (ChaCha8), inter versiones et machinas.
+    /// Teste que les valeurs d’une graine restent fixes (ChaCha8), entre versions et machines.
+    #[test]
+    fn probatio_semen_aureum() {
+        let aureum = Tensor1D::fortuitus_ex_semine(3, 42);
+        assert_eq!(aureum.materia, vec![0.6818961923066714, 0.950275407672484, 0.4275164028565197]);
+    }
+
+    /// Probat archiva formae veteris (materia nidificata) adhuc restitui.
+    /// Teste que les fichiers de l’ancien format (materia imbriquée) se restaurent encore.
+    #[test]
+    fn probatio_forma_vetus() {
+        let tensor: Tensor2D = serde_json::from_str(r#"{"materia":[[1.0,2.0],[3.0,4.0]],"ordines":2,"columnae":2}"#).unwrap();
+        assert_eq!(tensor, Tensor2D::ex_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]));
+        let tensor: Tensor3D = serde_json::from_str(
+            r#"{"materia":[[[1.0,2.0]],[[3.0,4.0]]],"profunditas":2,"altitudo":1,"latitudo":2}"#,
+        )
+        .unwrap();
+        assert_eq!(tensor, Tensor3D::ex_vec(vec![vec![vec![1.0, 2.0]], vec![vec![3.0, 4.0]]]));
+    }
+
+    /// Probat archivum Tensor2D truncatum vel inaequale errorem reddere.
+    /// Teste qu’un fichier Tensor2D tronqué ou irrégulier donne une erreur.
+    #[test]
+    fn probatio_tensor2d_archivum_invalidum() {
+        assert!(serde_json::from_str::<Tensor2D>(r#"{"elementa":[1.0,2.0,3.0],"ordines":2,"columnae":2}"#).is_err());
+        assert!(serde_json::from_str::<Tensor2D>(r#"{"materia":[[1.0,2.0],[3.0]],"ordines":2,"columnae":2}"#).is_err());
+        assert!(serde_json::from_str::<Tensor2D>(r#"{"materia":[[1.0,2.0]],"ordines":2,"columnae":2}"#).is_err());
+    }
+
+    /// Probat archivum Tensor2D integrum restitui.
+    /// Teste qu’un fichier Tensor2D complet est restauré.
+    #[test]
+    fn probatio_tensor2d_archivum_integrum() {
+        let integrum: Tensor2D = serde_json::from_str(r#"{"elementa":[1.0,2.0,3.0,4.0],"ordines":2,"columnae":2}"#).unwrap();
+        assert_eq!(integrum, Tensor2D::ex_elementis(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+    }
+
+    /// Probat archivum Tensor1D cum magnitudine discrepante errorem reddere.
+    /// Teste qu’un fichier Tensor1D de taille incohérente donne une erreur.
+    #[test]
+    fn probatio_tensor1d_archivum_invalidum() {
+        assert!(serde_json::from_str::<Tensor1D>(r#"{"materia":[1.0],"magnitudo":2}"#).is_err());
+    }
+
+    /// Probat archivum Tensor3D truncatum errorem reddere.
+    /// Teste qu’un fichier Tensor3D tronqué donne une erreur.
+    #[test]
+    fn probatio_tensor3d_archivum_invalidum() {
+        assert!(serde_json::from_str::<Tensor3D>(
+            r#"{"elementa":[1.0,2.0,3.0],"profunditas":2,"altitudo":1,"latitudo":2}"#
+        ).is_err());
+    }
+}
@@ -0,0 +1,515 @@
+//
+// DECOMPOSITIONES MATRICUM
+// ------------------------
+// LU, QR, Cholesky, déterminant, inverse, moindres carrés et valeurs propres symétriques.
+//
+
+use super::{ErrorTensoris, Numerus, TensorT1D, TensorT2D};
+
+/// Tolerantia relativa sub qua pivot (vel elementum diagonale) nullum habetur.
+/// Tolérance relative en dessous de laquelle un pivot est considéré comme nul.
+const TOLERANTIA: f64 = 1e-12;
+
+/// Tolerantia pro typo dato: numquam minor quam pauca epsilon machinae (ad f32).
+/// Tolérance pour le type donné : jamais inférieure à quelques epsilon machine (pour f32).
+fn tolerantia<T: Numerus>() -> T {
+    T::ex_f64(TOLERANTIA).max(T::EPSILON * T::ex_f64(16.0))
+}
+
+/// Numerus maximus circuituum methodi Jacobi.
+/// Nombre maximal de balayages de la méthode de Jacobi.
+const CIRCUITUS_JACOBI: usize = 100;
+
+/// Decompositio LU cum pivotatione partiali: P·A = L·U.
+/// Décomposition LU avec pivot partiel : P·A = L·U.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecompositioLU<T = f64> {
+    /// L (sub diagonali, diagonalis unitaria implicita) et U (diagonalis et supra) in una matrice.
+/// L (sous la diagonale, diagonale unité implicite) et U (diagonale et au-dessus) dans une matrice.
+    pub lu: TensorT2D<T>,
+
+    /// Ordo i matricis permutatae est ordo `permutatio[i]` originalis.
+/// La ligne i de la matrice permutée est la ligne `permutatio[i]` de l’originale.
+    pub permutatio: Vec<usize>,
+
+    /// Signum permutationis (+1 vel −1).
+/// Signe de la permutation (+1 ou −1).
+    pub signum: T,
+}
+
+/// Decompositio QR per reflexiones Householder: A = Q·R.
+/// Décomposition QR par réflexions de Householder : A = Q·R.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecompositioQR<T = f64> {
+    /// Matrix orthogonalis [m x m].
+/// Matrice orthogonale [m x m].
+    pub q: TensorT2D<T>,
+
+    /// Matrix triangularis superior [m x n].
+/// Matrice triangulaire supérieure [m x n].
+    pub r: TensorT2D<T>,
+}
+
+/// Decompositio propria matricis symmetricae: A = V·diag(λ)·Vᵀ.
+/// Décomposition propre d’une matrice symétrique : A = V·diag(λ)·Vᵀ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecompositioPropria<T = f64> {
+    /// Valores proprii, ordine crescente.
+/// Valeurs propres, par ordre croissant.
+    pub valores: TensorT1D<T>,
+
+    /// Vectores proprii in columnis, eodem ordine ac valores.
+/// Vecteurs propres en colonnes, dans le même ordre que les valeurs.
+    pub vectores: TensorT2D<T>,
+}
+
+impl<T: Numerus> DecompositioLU<T> {
+    /// Matrix L (triangularis inferior, diagonalis unitaria).
+/// Matrice L (triangulaire inférieure, diagonale unité).
+    pub fn l(&self) -> TensorT2D<T> {
+        let n = self.lu.ordines;
+        let mut l = TensorT2D::identitas(n);
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    /// Matrix U (triangularis superior).
+/// Matrice U (triangulaire supérieure).
+    pub fn u(&self) -> TensorT2D<T> {
+        let n = self.lu.ordines;
+        let mut u = TensorT2D::nullus(n, n);
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    /// Determinans: signum × productum diagonalis U.
+/// Déterminant : signe × produit de la diagonale de U.
+    pub fn determinans(&self) -> T {
+        self.signum * self.lu.diagonalis().materia.into_iter().product::<T>()
+    }
+
+    /// Solve A·X = B pro omnibus columnis B.
+/// Résout A·X = B pour toutes les colonnes de B.
+    pub fn solve(&self, b: &TensorT2D<T>) -> Result<TensorT2D<T>, ErrorTensoris> {
+        let n = self.lu.ordines;
+        if b.ordines != n {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "DecompositioLU::solve",
+                exspectata: vec![n, b.columnae],
+                reperta: b.forma().to_vec(),
+            });
+        }
+
+        // Permutatio et substitutio antea (L·Y = P·B).
+        // Permutation puis substitution avant (L·Y = P·B).
+        let mut y = TensorT2D::nullus(n, b.columnae);
+        for i in 0..n {
+            y.ordo_mut(i).copy_from_slice(b.ordo(self.permutatio[i]));
+            for k in 0..i {
+                let factor = self.lu[(i, k)];
+                for j in 0..b.columnae {
+                    let y_kj = y[(k, j)];
+                    y[(i, j)] -= factor * y_kj;
+                }
+            }
+        }
+
+        Ok(substitutio_retro(&self.lu, y))
+    }
+}
+
+impl<T: Numerus> TensorT2D<T> {
+    /// Decompositio LU cum pivotatione partiali; errorem reddit si matrix singularis est.
+/// Décomposition LU avec pivot partiel ; erreur si la matrice est singulière.
+    pub fn lu(&self) -> Result<DecompositioLU<T>, ErrorTensoris> {
+        let (decompositio, singularis) = self.factorisa_lu("Tensor2D::lu")?;
+        if singularis {
+            return Err(ErrorTensoris::Singularis { operatio: "Tensor2D::lu" });
+        }
+        Ok(decompositio)
+    }
+
+    /// Determinans matricis quadratae (0 si singularis est).
+/// Déterminant d’une matrice carrée (0 si elle est singulière).
+    pub fn determinans(&self) -> Result<T, ErrorTensoris> {
+        let (decompositio, singularis) = self.factorisa_lu("Tensor2D::determinans")?;
+        Ok(if singularis { T::NULLUS } else { decompositio.determinans() })
+    }
+
+    /// Matrix inversa per decompositionem LU.
+/// Matrice inverse via la décomposition LU.
+    pub fn inversa(&self) -> Result<TensorT2D<T>, ErrorTensoris> {
+        self.lu()?.solve(&TensorT2D::identitas(self.ordines))
+    }
+
+    /// Solve systema A·X = B (A quadrata et non singularis).
+/// Résout le système A·X = B (A carrée et non singulière).
+    pub fn solve(&self, b: &TensorT2D<T>) -> Result<TensorT2D<T>, ErrorTensoris> {
+        self.lu()?.solve(b)
+    }
+
+    /// Decompositio QR per reflexiones Householder (omnis forma admittitur).
+/// Décomposition QR par réflexions de Householder (toute forme acceptée).
+    pub fn qr(&self) -> DecompositioQR<T> {
+        let (m, n) = (self.ordines, self.columnae);
+        let mut q = TensorT2D::identitas(m);
+        let mut r = self.clone();
+        let duo = T::ex_f64(2.0);
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            // Vector Householder v, ut (I − 2vvᵀ)·x = α·e₁.
+            // Vecteur de Householder v tel que (I − 2vvᵀ)·x = α·e₁.
+            let mut v: Vec<T> = (k..m).map(|i| r[(i, k)]).collect();
+            let norma = v.iter().map(|&x| x * x).sum::<T>().sqrt();
+            if norma == T::NULLUS {
+                continue;
+            }
+            let alpha = if v[0] >= T::NULLUS { norma } else { -norma };
+            v[0] += alpha;
+            let norma_v = v.iter().map(|&x| x * x).sum::<T>().sqrt();
+            v.iter_mut().for_each(|x| *x /= norma_v);
+
+            // R ← H·R (columnae k..n)
+            for j in k..n {
+                let productum: T = v.iter().enumerate().map(|(t, &vt)| vt * r[(k + t, j)]).sum();
+                for (t, vt) in v.iter().enumerate() {
+                    r[(k + t, j)] -= duo * *vt * productum;
+                }
+            }
+
+            // Q ← Q·H
+            for i in 0..m {
+                let productum: T = v.iter().enumerate().map(|(t, &vt)| q[(i, k + t)] * vt).sum();
+                for (t, vt) in v.iter().enumerate() {
+                    q[(i, k + t)] -= duo * productum * *vt;
+                }
+            }
+
+            for i in k + 1..m {
+                r[(i, k)] = T::NULLUS;
+            }
+        }
+
+        DecompositioQR { q, r }
+    }
+
+    /// Solutio minimorum quadratorum: X minimizans ‖A·X − B‖ (ordines ≥ columnae, rango pleno).
+/// Solution des moindres carrés : X minimisant ‖A·X − B‖ (lignes ≥ colonnes, rang plein).
+    pub fn minimi_quadrati(&self, b: &TensorT2D<T>) -> Result<TensorT2D<T>, ErrorTensoris> {
+        let operatio = "Tensor2D::minimi_quadrati";
+        let (m, n) = (self.ordines, self.columnae);
+        if m < n {
+            return Err(ErrorTensoris::Subdeterminatum { operatio, ordines: m, columnae: n });
+        }
+        if b.ordines != m {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio,
+                exspectata: vec![m, b.columnae],
+                reperta: b.forma().to_vec(),
+            });
+        }
+
+        let DecompositioQR { q, r } = self.qr();
+        let tolerantia = tolerantia::<T>() * self.norma_maxima() * T::ex_f64(m as f64);
+        if (0..n).any(|i| r[(i, i)].abs() <= tolerantia || r[(i, i)] == T::NULLUS) {
+            return Err(ErrorTensoris::Singularis { operatio });
+        }
+
+        // R₁·X = (Qᵀ·B)[0..n]
+        let c = q.transpone().productum_matriciale(b).seca_ordines(0..n);
+        Ok(substitutio_retro(&r, c))
+    }
+
+    /// Decompositio Cholesky A = L·Lᵀ pro matrice symmetrica definita positiva.
+/// Décomposition de Cholesky A = L·Lᵀ d’une matrice symétrique définie positive.
+    pub fn cholesky(&self) -> Result<TensorT2D<T>, ErrorTensoris> {
+        let operatio = "Tensor2D::cholesky";
+        self.verifica_symmetriam(operatio)?;
+
+        let n = self.ordines;
+        let mut l = TensorT2D::nullus(n, n);
+        for j in 0..n {
+            let diagonale = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<T>();
+            if diagonale <= T::NULLUS {
+                return Err(ErrorTensoris::NonDefinitaPositiva { operatio });
+            }
+            l[(j, j)] = diagonale.sqrt();
+
+            for i in j + 1..n {
+                let summa: T = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - summa) / l[(j, j)];
+            }
+        }
+
+        Ok(l)
+    }
+
+    /// Decompositio propria matricis symmetricae (methodus Jacobi cyclica).
+/// Décomposition propre d’une matrice symétrique (méthode de Jacobi cyclique).
+    pub fn propria_symmetrica(&self) -> Result<DecompositioPropria<T>, ErrorTensoris> {
+        self.verifica_symmetriam("Tensor2D::propria_symmetrica")?;
+
+        let n = self.ordines;
+        let mut a = self.clone();
+        let mut v = TensorT2D::identitas(n);
+        let limen = (tolerantia::<T>() * self.norma_frobenii()).powi(2);
+        let (unus, duo) = (T::UNUS, T::ex_f64(2.0));
+
+        for _ in 0..CIRCUITUS_JACOBI {
+            let extra: T = (0..n).flat_map(|i| (0..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| i != j)
+                .map(|(i, j)| a[(i, j)] * a[(i, j)])
+                .sum();
+            if extra <= limen {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[(p, q)] == T::NULLUS {
+                        continue;
+                    }
+
+                    // Rotatio quae a[p][q] annullat.
+                    // Rotation qui annule a[p][q].
+                    let theta = (a[(q, q)] - a[(p, p)]) / (duo * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + unus).sqrt());
+                    let c = unus / (t * t + unus).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        // Ordina valores crescente, vectores simul.
+        // Trie les valeurs par ordre croissant, les vecteurs avec.
+        let mut ordo: Vec<usize> = (0..n).collect();
+        ordo.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+
+        let valores = TensorT1D::ex_vec(ordo.iter().map(|&i| a[(i, i)]).collect());
+        let mut vectores = TensorT2D::nullus(n, n);
+        for (novus, &vetus) in ordo.iter().enumerate() {
+            for k in 0..n {
+                vectores[(k, novus)] = v[(k, vetus)];
+            }
+        }
+
+        Ok(DecompositioPropria { valores, vectores })
+    }
+
+    /// Factorisatio LU interna; reddit etiam an pivot nullum inventum sit.
+/// Factorisation LU interne ; indique aussi si un pivot nul a été rencontré.
+    fn factorisa_lu(&self, operatio: &'static str) -> Result<(DecompositioLU<T>, bool), ErrorTensoris> {
+        self.verifica_quadratam(operatio)?;
+
+        let n = self.ordines;
+        let mut lu = self.clone();
+        let mut permutatio: Vec<usize> = (0..n).collect();
+        let mut signum = T::UNUS;
+        let mut singularis = false;
+        let tolerantia = tolerantia::<T>() * self.norma_maxima() * T::ex_f64(n as f64);
+
+        for k in 0..n {
+            // Pivot: maximum absolutum in columna k.
+            // Pivot : plus grande valeur absolue de la colonne k.
+            let pivot = (k..n).max_by(|&i, &j| lu[(i, k)].abs().total_cmp(&lu[(j, k)].abs())).unwrap();
+            if lu[(pivot, k)].abs() <= tolerantia {
+                singularis = true;
+                continue;
+            }
+
+            if pivot != k {
+                for j in 0..n {
+                    lu.elementa.swap(k * n + j, pivot * n + j);
+                }
+                permutatio.swap(k, pivot);
+                signum = -signum;
+            }
+
+            for i in k + 1..n {
+                let diagonale = lu[(k, k)];
+                lu[(i, k)] /= diagonale;
+                let factor = lu[(i, k)];
+                for j in k + 1..n {
+                    let lu_kj = lu[(k, j)];
+                    lu[(i, j)] -= factor * lu_kj;
+                }
+            }
+        }
+
+        Ok((DecompositioLU { lu, permutatio, signum }, singularis))
+    }
+
+    /// Verifica matricem quadratam esse.
+/// Vérifie que la matrice est carrée.
+    fn verifica_quadratam(&self, operatio: &'static str) -> Result<(), ErrorTensoris> {
+        if self.ordines != self.columnae {
+            return Err(ErrorTensoris::NonQuadrata { operatio, forma: self.forma().to_vec() });
+        }
+        Ok(())
+    }
+
+    /// Verifica matricem quadratam et (intra tolerantiam) symmetricam esse.
+/// Vérifie que la matrice est carrée et symétrique (à la tolérance près).
+    fn verifica_symmetriam(&self, operatio: &'static str) -> Result<(), ErrorTensoris> {
+        self.verifica_quadratam(operatio)?;
+
+        let tolerantia = tolerantia::<T>() * T::ex_f64(100.0) * self.norma_maxima().max(T::UNUS);
+        for i in 0..self.ordines {
+            for j in i + 1..self.columnae {
+                if (self[(i, j)] - self[(j, i)]).abs() > tolerantia {
+                    return Err(ErrorTensoris::NonSymmetrica { operatio });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Substitutio retro: solve U·X = Y, ubi U pars superior primarum n ordinum matricis `u` est.
+/// Substitution arrière : résout U·X = Y avec U la partie supérieure des n premières lignes de `u`.
+fn substitutio_retro<T: Numerus>(u: &TensorT2D<T>, mut y: TensorT2D<T>) -> TensorT2D<T> {
+    let n = y.ordines;
+    for i in (0..n).rev() {
+        for k in i + 1..n {
+            let factor = u[(i, k)];
+            for j in 0..y.columnae {
+                let y_kj = y[(k, j)];
+                y[(i, j)] -= factor * y_kj;
+            }
+        }
+        let diagonale = u[(i, i)];
+        y.ordo_mut(i).iter_mut().for_each(|x| *x /= diagonale);
+    }
+    y
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::super::Tensor2D;
+    use super::ErrorTensoris;
+
+    fn prope(a: &Tensor2D, b: &Tensor2D) -> bool {
+        (a - b).norma_maxima() < 1e-9
+    }
+
+    /// Matrix quadrata invertibilis, non symmetrica.
+    /// Matrice carrée inversible, non symétrique.
+    fn a() -> Tensor2D {
+        Tensor2D::ex_vec(vec![vec![0.0, 2.0, 1.0], vec![1.0, 1.0, 0.0], vec![3.0, 0.0, 1.0]])
+    }
+
+    /// Matrix symmetrica definita positiva.
+    /// Matrice symétrique définie positive.
+    fn s() -> Tensor2D {
+        Tensor2D::ex_vec(vec![vec![4.0, 2.0, 0.0], vec![2.0, 5.0, 1.0], vec![0.0, 1.0, 3.0]])
+    }
+
+    /// Probat L·U matricem permutatam P·A reddere.
+    /// Teste que L·U redonne la matrice permutée P·A.
+    #[test]
+    fn probatio_lu() {
+        let a = a();
+        let lu = a.lu().unwrap();
+        let mut pa = Tensor2D::nullus(3, 3);
+        for (i, &p) in lu.permutatio.iter().enumerate() {
+            pa.ordo_mut(i).copy_from_slice(a.ordo(p));
+        }
+        assert!(prope(&lu.l().productum_matriciale(&lu.u()), &pa));
+    }
+
+    /// Probat determinantem et inversam.
+    /// Teste le déterminant et l’inverse.
+    #[test]
+    fn probatio_determinans_et_inversa() {
+        let a = a();
+        assert!((a.determinans().unwrap() + 5.0).abs() < 1e-12);
+        assert!(prope(&a.productum_matriciale(&a.inversa().unwrap()), &Tensor2D::identitas(3)));
+    }
+
+    /// Probat Q orthonormalem esse et Q·R matricem reddere.
+    /// Teste que Q est orthonormée et que Q·R redonne la matrice.
+    #[test]
+    fn probatio_qr() {
+        let a = a();
+        let qr = a.qr();
+        assert!(prope(&qr.q.productum_matriciale(&qr.r), &a));
+        assert!(prope(&qr.q.transpone().productum_matriciale(&qr.q), &Tensor2D::identitas(3)));
+    }
+
+    /// Probat rectam y = 1 + 2x per minimos quadratos inveniri et formas invalidas reici.
+    /// Teste que la droite y = 1 + 2x est retrouvée par moindres carrés et que les formes invalides sont rejetées.
+    #[test]
+    fn probatio_minimi_quadrati() {
+        let x = Tensor2D::ex_vec(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0], vec![1.0, 3.0]]);
+        let y = Tensor2D::ex_vec(vec![vec![1.0], vec![3.0], vec![5.0], vec![7.0]]);
+        assert!(prope(&x.minimi_quadrati(&y).unwrap(), &Tensor2D::ex_vec(vec![vec![1.0], vec![2.0]])));
+        assert!(matches!(
+            x.transpone().minimi_quadrati(&Tensor2D::nullus(2, 1)),
+            Err(ErrorTensoris::Subdeterminatum { ordines: 2, columnae: 4, .. })
+        ));
+        assert!(matches!(x.minimi_quadrati(&Tensor2D::nullus(3, 1)), Err(ErrorTensoris::FormaeDiscrepantes { .. })));
+    }
+
+    /// Probat L·Lᵀ matricem Cholesky reddere.
+    /// Teste que L·Lᵀ redonne la matrice de Cholesky.
+    #[test]
+    fn probatio_cholesky() {
+        let s = s();
+        let l = s.cholesky().unwrap();
+        assert!(prope(&l.productum_matriciale(&l.transpone()), &s));
+    }
+
+    /// Probat V·Λ·Vᵀ matricem reddere, valoribus propriis ascendentibus.
+    /// Teste que V·Λ·Vᵀ redonne la matrice, avec des valeurs propres croissantes.
+    #[test]
+    fn probatio_propria_symmetrica() {
+        let s = s();
+        let propria = s.propria_symmetrica().unwrap();
+        let lambda = Tensor2D::ex_diagonali(&propria.valores);
+        let v = &propria.vectores;
+        assert!(prope(&v.productum_matriciale(&lambda).productum_matriciale(&v.transpone()), &s));
+        assert!(propria.valores.materia.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    /// Probat matricem singularem determinantem nullum habere nec inverti.
+    /// Teste qu’une matrice singulière a un déterminant nul et n’est pas inversible.
+    #[test]
+    fn probatio_singularis() {
+        let singularis = Tensor2D::ex_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singularis.determinans(), Ok(0.0));
+        assert!(matches!(singularis.inversa(), Err(ErrorTensoris::Singularis { .. })));
+        assert!(matches!(singularis.cholesky(), Err(ErrorTensoris::NonDefinitaPositiva { .. })));
+    }
+
+    /// Probat formas non idoneas errorem reddere.
+    /// Teste que des formes inadaptées donnent une erreur.
+    #[test]
+    fn probatio_formae_non_idoneae() {
+        assert!(matches!(Tensor2D::nullus(4, 2).lu(), Err(ErrorTensoris::NonQuadrata { .. })));
+        assert!(matches!(a().propria_symmetrica(), Err(ErrorTensoris::NonSymmetrica { .. })));
+    }
+}
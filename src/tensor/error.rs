@@ -0,0 +1,105 @@
+use std::fmt;
+
+/// Error operationis tensorum: formae inputorum non conveniunt.
+/// Erreur d’une opération sur tenseurs : les formes des entrées ne conviennent pas.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorTensoris {
+    /// Formae operandorum non congruunt (e.g. in additione vel productu).
+/// Les formes des opérandes ne correspondent pas.
+    FormaeDiscrepantes {
+        operatio: &'static str,
+        exspectata: Vec<usize>,
+        reperta: Vec<usize>,
+    },
+
+    /// Materia nulla data est, sed saltem unum elementum requiritur.
+/// Aucune donnée fournie alors qu’au moins un élément est requis.
+    MateriaVacua { operatio: &'static str },
+
+    /// Ordo (vel planum) materiae longitudinem ceteris diversam habet.
+/// Une ligne (ou un plan) n’a pas la même longueur que les autres.
+    OrdoInaequalis {
+        operatio: &'static str,
+        index: Vec<usize>,
+        exspectata: usize,
+        reperta: usize,
+    },
+
+    /// Operatio matricem quadratam requirit.
+/// L’opération exige une matrice carrée.
+    NonQuadrata { operatio: &'static str, forma: Vec<usize> },
+
+    /// Matrix singularis est (vel numerice fere singularis).
+/// La matrice est singulière (ou numériquement presque singulière).
+    Singularis { operatio: &'static str },
+
+    /// Operatio matricem symmetricam requirit.
+/// L’opération exige une matrice symétrique.
+    NonSymmetrica { operatio: &'static str },
+
+    /// Matrix definita positiva non est.
+/// La matrice n’est pas définie positive.
+    NonDefinitaPositiva { operatio: &'static str },
+
+    /// Systema subdeterminatum: pauciores ordines (aequationes) quam columnae (incognitae).
+/// Système sous-déterminé : moins de lignes (équations) que de colonnes (inconnues).
+    Subdeterminatum { operatio: &'static str, ordines: usize, columnae: usize },
+
+    /// Axis extra rangum tensoris est.
+/// L’axe dépasse le rang du tenseur.
+    AxisInvalidus { operatio: &'static str, axis: usize, rangus: usize },
+
+    /// Axes permutationem rangi non formant.
+/// Les axes ne forment pas une permutation valide.
+    PermutatioInvalida { operatio: &'static str, axes: Vec<usize> },
+
+    /// Rangus tensoris (numerus axium) non convenit.
+/// Le rang du tenseur (nombre d’axes) ne convient pas.
+    RangusDiscrepans { operatio: &'static str, exspectatus: usize, repertus: usize },
+}
+
+impl fmt::Display for ErrorTensoris {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorTensoris::FormaeDiscrepantes { operatio, exspectata, reperta } => write!(
+                f,
+                "{}: forma {:?} exspectata, {:?} reperta.",
+                operatio, exspectata, reperta
+            ),
+            ErrorTensoris::MateriaVacua { operatio } => {
+                write!(f, "{}: saltem unum elementum esse debet.", operatio)
+            }
+            ErrorTensoris::OrdoInaequalis { operatio, index, exspectata, reperta } => write!(
+                f,
+                "{}: ordo {:?} longitudinem {} habet, {} exspectata.",
+                operatio, index, reperta, exspectata
+            ),
+            ErrorTensoris::NonQuadrata { operatio, forma } => {
+                write!(f, "{}: matrix quadrata requiritur, forma {:?} reperta.", operatio, forma)
+            }
+            ErrorTensoris::Singularis { operatio } => write!(f, "{}: matrix singularis est.", operatio),
+            ErrorTensoris::NonSymmetrica { operatio } => {
+                write!(f, "{}: matrix symmetrica requiritur.", operatio)
+            }
+            ErrorTensoris::NonDefinitaPositiva { operatio } => {
+                write!(f, "{}: matrix definita positiva non est.", operatio)
+            }
+            ErrorTensoris::Subdeterminatum { operatio, ordines, columnae } => write!(
+                f,
+                "{}: systema subdeterminatum, {} aequationes pro {} incognitis.",
+                operatio, ordines, columnae
+            ),
+            ErrorTensoris::AxisInvalidus { operatio, axis, rangus } => {
+                write!(f, "{}: axis {} extra tensorem rangi {} est.", operatio, axis, rangus)
+            }
+            ErrorTensoris::PermutatioInvalida { operatio, axes } => {
+                write!(f, "{}: axes {:?} permutatio valida non sunt.", operatio, axes)
+            }
+            ErrorTensoris::RangusDiscrepans { operatio, exspectatus, repertus } => {
+                write!(f, "{}: rangus {} exspectatus, {} repertus.", operatio, exspectatus, repertus)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErrorTensoris {}
@@ -0,0 +1,325 @@
+//
+// INSTRUMENTA MATRICUM
+// --------------------
+// Transposition, fonctions élément par élément, réductions, découpage et concaténation.
+//
+
+use super::{ErrorTensoris, Numerus, TensorT1D, TensorT2D};
+use std::ops::Range;
+
+impl<T: Numerus> TensorT2D<T> {
+    /// Matrix identitatis [n x n].
+/// Matrice identité [n x n].
+    pub fn identitas(n: usize) -> Self {
+        let mut identitas = Self::nullus(n, n);
+        for i in 0..n {
+            identitas[(i, i)] = T::UNUS;
+        }
+        identitas
+    }
+
+    /// Matrix diagonalis ex vectore: diagonalis[(i, i)] = vector[i].
+/// Matrice diagonale construite à partir d’un vecteur.
+    pub fn ex_diagonali(diagonalis: &TensorT1D<T>) -> Self {
+        let mut matrix = Self::nullus(diagonalis.magnitudo, diagonalis.magnitudo);
+        for (i, &valor) in diagonalis.materia.iter().enumerate() {
+            matrix[(i, i)] = valor;
+        }
+        matrix
+    }
+
+    /// Diagonalis principalis (longitudo min(ordines, columnae)).
+/// Diagonale principale (longueur min(lignes, colonnes)).
+    pub fn diagonalis(&self) -> TensorT1D<T> {
+        TensorT1D::ex_vec((0..self.ordines.min(self.columnae)).map(|i| self[(i, i)]).collect())
+    }
+
+    /// Transposita matricis: [ordines x columnae] → [columnae x ordines].
+/// Transposée de la matrice.
+    pub fn transpone(&self) -> Self {
+        let mut elementa = Vec::with_capacity(self.elementa.len());
+        for j in 0..self.columnae {
+            elementa.extend(self.ordines_iter().map(|ordo| ordo[j]));
+        }
+        Self::ex_elementis(self.columnae, self.ordines, elementa)
+    }
+
+    /// Applica functionem ad omne elementum.
+/// Applique une fonction à chaque élément.
+    pub fn map(&self, f: impl Fn(T) -> T) -> Self {
+        Self::ex_elementis(self.ordines, self.columnae, self.elementa.iter().map(|&x| f(x)).collect())
+    }
+
+    /// Applica functionem ad paria elementorum duorum tensorum eiusdem formae.
+/// Applique une fonction aux paires d’éléments de deux tenseurs de même forme.
+    pub fn zip_map(&self, alius: &Self, f: impl Fn(T, T) -> T) -> Self {
+        self.try_zip_map(alius, f).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Sicut `zip_map`, errorem reddens si formae differunt.
+/// Comme `zip_map` ; renvoie une erreur si les formes diffèrent.
+    pub fn try_zip_map(&self, alius: &Self, f: impl Fn(T, T) -> T) -> Result<Self, ErrorTensoris> {
+        if self.forma() != alius.forma() {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "Tensor2D::zip_map",
+                exspectata: self.forma().to_vec(),
+                reperta: alius.forma().to_vec(),
+            });
+        }
+
+        let elementa = self.elementa.iter().zip(&alius.elementa).map(|(&a, &b)| f(a, b)).collect();
+        Ok(Self::ex_elementis(self.ordines, self.columnae, elementa))
+    }
+
+    /// Summa omnium elementorum.
+/// Somme de tous les éléments.
+    pub fn summa(&self) -> T {
+        self.elementa.iter().sum()
+    }
+
+    /// Media omnium elementorum (NaN si matrix vacua est).
+/// Moyenne de tous les éléments (NaN si la matrice est vide).
+    pub fn media(&self) -> T {
+        self.summa() / T::ex_f64(self.elementa.len() as f64)
+    }
+
+    /// Summa cuiusque ordinis (vector longitudinis `ordines`).
+/// Somme de chaque ligne (vecteur de taille `ordines`).
+    pub fn summa_ordinum(&self) -> TensorT1D<T> {
+        TensorT1D::ex_vec(self.ordines_iter().map(|ordo| ordo.iter().sum()).collect())
+    }
+
+    /// Summa cuiusque columnae (vector longitudinis `columnae`).
+/// Somme de chaque colonne (vecteur de taille `columnae`).
+    pub fn summa_columnarum(&self) -> TensorT1D<T> {
+        let mut summae = vec![T::NULLUS; self.columnae];
+        for ordo in self.ordines_iter() {
+            for (summa, valor) in summae.iter_mut().zip(ordo) {
+                *summa += *valor;
+            }
+        }
+        TensorT1D::ex_vec(summae)
+    }
+
+    /// Media cuiusque ordinis.
+/// Moyenne de chaque ligne.
+    pub fn media_ordinum(&self) -> TensorT1D<T> {
+        self.summa_ordinum().scale(T::UNUS / T::ex_f64(self.columnae as f64))
+    }
+
+    /// Media cuiusque columnae.
+/// Moyenne de chaque colonne.
+    pub fn media_columnarum(&self) -> TensorT1D<T> {
+        self.summa_columnarum().scale(T::UNUS / T::ex_f64(self.ordines as f64))
+    }
+
+    /// Index maximi in quoque ordine (primus, si plures aequales sunt).
+/// Indice du maximum de chaque ligne (le premier en cas d’égalité).
+    pub fn argmax_ordinum(&self) -> Vec<usize> {
+        self.ordines_iter()
+            .map(|ordo| {
+                ordo.iter()
+                    .enumerate()
+                    .fold((0, -T::INFINITUS), |(optimus, maximum), (j, &x)| {
+                        if x > maximum { (j, x) } else { (optimus, maximum) }
+                    })
+                    .0
+            })
+            .collect()
+    }
+
+    /// Minimum omnium elementorum (+∞ si matrix vacua est).
+/// Minimum de tous les éléments (+∞ si la matrice est vide).
+    pub fn minimum(&self) -> T {
+        self.elementa.iter().copied().fold(T::INFINITUS, T::min)
+    }
+
+    /// Maximum omnium elementorum (−∞ si matrix vacua est).
+/// Maximum de tous les éléments (−∞ si la matrice est vide).
+    pub fn maximum(&self) -> T {
+        self.elementa.iter().copied().fold(-T::INFINITUS, T::max)
+    }
+
+    /// Norma L1 elementorum: Σ|aᵢⱼ|.
+/// Norme L1 des éléments : Σ|aᵢⱼ|.
+    pub fn norma_l1(&self) -> T {
+        self.elementa.iter().map(|x| x.abs()).sum()
+    }
+
+    /// Norma Frobenii: √(Σ aᵢⱼ²).
+/// Norme de Frobenius : √(Σ aᵢⱼ²).
+    pub fn norma_frobenii(&self) -> T {
+        self.elementa.iter().map(|&x| x * x).sum::<T>().sqrt()
+    }
+
+    /// Norma maxima elementorum: max|aᵢⱼ|.
+/// Norme infinie des éléments : max|aᵢⱼ|.
+    pub fn norma_maxima(&self) -> T {
+        self.elementa.iter().fold(T::NULLUS, |maximum, x| maximum.max(x.abs()))
+    }
+
+    /// Vestigium (trace): summa diagonalis principalis.
+/// Trace : somme de la diagonale principale.
+    pub fn vestigium(&self) -> T {
+        self.diagonalis().materia.iter().sum()
+    }
+
+    /// Columna `j` ut vector.
+/// Colonne `j` sous forme de vecteur.
+    pub fn columna(&self, j: usize) -> TensorT1D<T> {
+        assert!(j < self.columnae, "Columna {} extra {} columnas est.", j, self.columnae);
+        TensorT1D::ex_vec(self.ordines_iter().map(|ordo| ordo[j]).collect())
+    }
+
+    /// Sectio ordinum `initium..finis` (copia).
+/// Extrait les lignes `initium..finis` (copie).
+    pub fn seca_ordines(&self, ambitus: Range<usize>) -> Self {
+        assert!(
+            ambitus.start <= ambitus.end && ambitus.end <= self.ordines,
+            "Sectio ordinum {:?} extra {} ordines est.",
+            ambitus,
+            self.ordines
+        );
+        let elementa = self.elementa[ambitus.start * self.columnae..ambitus.end * self.columnae].to_vec();
+        Self::ex_elementis(ambitus.len(), self.columnae, elementa)
+    }
+
+    /// Sectio columnarum `initium..finis` (copia).
+/// Extrait les colonnes `initium..finis` (copie).
+    pub fn seca_columnas(&self, ambitus: Range<usize>) -> Self {
+        assert!(
+            ambitus.start <= ambitus.end && ambitus.end <= self.columnae,
+            "Sectio columnarum {:?} extra {} columnas est.",
+            ambitus,
+            self.columnae
+        );
+        let mut elementa = Vec::with_capacity(self.ordines * ambitus.len());
+        for ordo in self.ordines_iter() {
+            elementa.extend_from_slice(&ordo[ambitus.clone()]);
+        }
+        Self::ex_elementis(self.ordines, ambitus.len(), elementa)
+    }
+
+    /// Concatenatio horizontalis: columnae matricum iuxta ponuntur (ordines aequales).
+/// Concaténation horizontale : les colonnes sont juxtaposées (même nombre de lignes).
+    pub fn concatena_horizontaliter(partes: &[&TensorT2D<T>]) -> Self {
+        Self::try_concatena_horizontaliter(partes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Concatenatio horizontalis, errorem reddens si ordines differunt.
+/// Concaténation horizontale ; renvoie une erreur si les nombres de lignes diffèrent.
+    pub fn try_concatena_horizontaliter(partes: &[&TensorT2D<T>]) -> Result<Self, ErrorTensoris> {
+        let operatio = "Tensor2D::concatena_horizontaliter";
+        let prima = partes.first().ok_or(ErrorTensoris::MateriaVacua { operatio })?;
+        for pars in partes {
+            if pars.ordines != prima.ordines {
+                return Err(ErrorTensoris::FormaeDiscrepantes {
+                    operatio,
+                    exspectata: vec![prima.ordines, pars.columnae],
+                    reperta: pars.forma().to_vec(),
+                });
+            }
+        }
+
+        let columnae = partes.iter().map(|pars| pars.columnae).sum();
+        let mut elementa = Vec::with_capacity(prima.ordines * columnae);
+        for i in 0..prima.ordines {
+            for pars in partes {
+                elementa.extend_from_slice(pars.ordo(i));
+            }
+        }
+        Ok(Self::ex_elementis(prima.ordines, columnae, elementa))
+    }
+
+    /// Concatenatio verticalis: ordines matricum superponuntur (columnae aequales).
+/// Concaténation verticale : les lignes sont empilées (même nombre de colonnes).
+    pub fn concatena_verticaliter(partes: &[&TensorT2D<T>]) -> Self {
+        Self::try_concatena_verticaliter(partes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Concatenatio verticalis, errorem reddens si columnae differunt.
+/// Concaténation verticale ; renvoie une erreur si les nombres de colonnes diffèrent.
+    pub fn try_concatena_verticaliter(partes: &[&TensorT2D<T>]) -> Result<Self, ErrorTensoris> {
+        let operatio = "Tensor2D::concatena_verticaliter";
+        let prima = partes.first().ok_or(ErrorTensoris::MateriaVacua { operatio })?;
+        for pars in partes {
+            if pars.columnae != prima.columnae {
+                return Err(ErrorTensoris::FormaeDiscrepantes {
+                    operatio,
+                    exspectata: vec![pars.ordines, prima.columnae],
+                    reperta: pars.forma().to_vec(),
+                });
+            }
+        }
+
+        let ordines = partes.iter().map(|pars| pars.ordines).sum();
+        let elementa = partes.iter().flat_map(|pars| pars.elementa.iter().copied()).collect();
+        Ok(Self::ex_elementis(ordines, prima.columnae, elementa))
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::super::{Tensor1D, Tensor2D};
+
+    fn a() -> Tensor2D {
+        Tensor2D::ex_vec(vec![vec![1.0, -5.0, 3.0], vec![4.0, 2.0, 0.0]])
+    }
+
+    /// Probat transpositionem, map et zip_map.
+    /// Teste la transposition, map et zip_map.
+    #[test]
+    fn probatio_transpone_et_map() {
+        let a = a();
+        assert_eq!(a.transpone().materia(), vec![vec![1.0, 4.0], vec![-5.0, 2.0], vec![3.0, 0.0]]);
+        assert_eq!(a.map(|x| x * 2.0)[(0, 1)], -10.0);
+        assert_eq!(a.zip_map(&a, |x, y| x - y).maximum(), 0.0);
+        assert!(a.try_zip_map(&a.transpone(), |x, y| x + y).is_err());
+    }
+
+    /// Probat reductiones: summas, medias, argmax, extrema, normas et vestigium.
+    /// Teste les réductions : sommes, moyennes, argmax, extrema, normes et trace.
+    #[test]
+    fn probatio_reductiones() {
+        let a = a();
+        assert_eq!(a.summa_ordinum().materia, vec![-1.0, 6.0]);
+        assert_eq!(a.summa_columnarum().materia, vec![5.0, -3.0, 3.0]);
+        assert_eq!(a.media_columnarum().materia, vec![2.5, -1.5, 1.5]);
+        assert_eq!(a.argmax_ordinum(), vec![2, 0]);
+        assert_eq!((a.minimum(), a.maximum()), (-5.0, 4.0));
+        assert_eq!((a.norma_l1(), a.norma_maxima()), (15.0, 5.0));
+        assert!((a.norma_frobenii() - 55.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(a.vestigium(), 3.0);
+    }
+
+    /// Probat matrices diagonales et identitatis.
+    /// Teste les matrices diagonales et identité.
+    #[test]
+    fn probatio_diagonalis() {
+        let d = Tensor2D::ex_diagonali(&Tensor1D::ex_vec(vec![2.0, 3.0]));
+        assert_eq!(d.productum_matriciale(&Tensor2D::identitas(2)), d);
+        assert_eq!(d.diagonalis().materia, vec![2.0, 3.0]);
+    }
+
+    /// Probat columnas et sectiones ordinum vel columnarum.
+    /// Teste les colonnes et le découpage de lignes ou de colonnes.
+    #[test]
+    fn probatio_sectiones() {
+        let a = a();
+        assert_eq!(a.columna(1).materia, vec![-5.0, 2.0]);
+        assert_eq!(a.seca_ordines(1..2).materia(), vec![vec![4.0, 2.0, 0.0]]);
+        assert_eq!(a.seca_columnas(1..3).materia(), vec![vec![-5.0, 3.0], vec![2.0, 0.0]]);
+    }
+
+    /// Probat concatenationem sectiones reiungere et formas discrepantes reicere.
+    /// Teste que la concaténation réassemble les découpes et rejette les formes incompatibles.
+    #[test]
+    fn probatio_concatenatio() {
+        let a = a();
+        let h = Tensor2D::concatena_horizontaliter(&[&a.seca_columnas(0..1), &a.seca_columnas(1..3)]);
+        let v = Tensor2D::concatena_verticaliter(&[&a.seca_ordines(0..1), &a.seca_ordines(1..2)]);
+        assert_eq!(h, a);
+        assert_eq!(v, a);
+        assert!(Tensor2D::try_concatena_verticaliter(&[&a, &Tensor2D::identitas(2)]).is_err());
+    }
+}
@@ -0,0 +1,522 @@
+//
+// TENSOR MULTIDIMENSIONALIS (N-D)
+// -------------------------------
+// Tenseur de rang quelconque : remodelage, permutation d’axes, réductions.
+//
+
+use super::{ErrorTensoris, Numerus, TensorT1D, TensorT2D, TensorT3D};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Tensor cuiusvis rangi, elementis contiguis ordine "row-major" (ultimus axis celerrimus).
+/// Tenseur de rang quelconque, éléments contigus en ordre ligne (le dernier axe varie le plus vite).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "FormaND<T>")]
+pub struct TensorTND<T> {
+    /// Materia interna: memoria contigua.
+/// Données internes : tampon contigu.
+    pub elementa: Vec<T>,
+
+    /// Magnitudo cuiusque axis.
+/// Taille de chaque axe.
+    pub dimensiones: Vec<usize>,
+}
+
+/// Tensor N-dimensionalis elementorum f64, typus praefinitus; `TensorTND::<f32>` pro f32.
+/// Tenseur N-D à éléments f64, le type par défaut ; `TensorTND::<f32>` pour f32.
+pub type TensorND = TensorTND<f64>;
+
+/// Forma serializata TensorND, ante verificationem numeri elementorum.
+/// Forme sérialisée d’un TensorND, avant vérification du nombre d’éléments.
+#[derive(Deserialize)]
+struct FormaND<T> {
+    elementa: Vec<T>,
+    dimensiones: Vec<usize>,
+}
+
+impl<T> TryFrom<FormaND<T>> for TensorTND<T> {
+    type Error = ErrorTensoris;
+
+    fn try_from(FormaND { elementa, dimensiones }: FormaND<T>) -> Result<Self, ErrorTensoris> {
+        Self::try_ex_elementis(&dimensiones, elementa)
+    }
+}
+
+// Constructor formam verificans, sine vinculo `Numerus`, ut deserializatio quoque eum adhibeat.
+// Constructeur vérifiant la forme, sans contrainte `Numerus`, pour que la désérialisation l’utilise aussi.
+impl<T> TensorTND<T> {
+    /// Ex memoria contigua, errorem reddens si numerus elementorum formae non convenit.
+/// Crée un tenseur depuis un tampon ; erreur si le nombre d’éléments ne correspond pas.
+    pub fn try_ex_elementis(dimensiones: &[usize], elementa: Vec<T>) -> Result<Self, ErrorTensoris> {
+        let magnitudo: usize = dimensiones.iter().product();
+        if elementa.len() != magnitudo {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "TensorND::ex_elementis",
+                exspectata: dimensiones.to_vec(),
+                reperta: vec![elementa.len()],
+            });
+        }
+        Ok(Self { elementa, dimensiones: dimensiones.to_vec() })
+    }
+}
+
+impl<T: Numerus> TensorTND<T> {
+    /// Ex memoria contigua et forma data.
+/// Crée un tenseur depuis un tampon contigu et une forme.
+    pub fn ex_elementis(dimensiones: &[usize], elementa: Vec<T>) -> Self {
+        Self::try_ex_elementis(dimensiones, elementa).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Tensor nullus datae formae.
+/// Tenseur rempli de zéros.
+    pub fn nullus(dimensiones: &[usize]) -> Self {
+        let magnitudo = dimensiones.iter().product();
+        Self { elementa: vec![T::NULLUS; magnitudo], dimensiones: dimensiones.to_vec() }
+    }
+
+    /// Tensor fortuitus (valores inter 0 et 1).
+/// Tenseur de valeurs aléatoires entre 0 et 1.
+    pub fn fortuitus(dimensiones: &[usize]) -> Self {
+        Self::fortuitus_cum(dimensiones, &mut rand::rng())
+    }
+
+    /// Tensor fortuitus ex generatore dato.
+/// Tenseur aléatoire tiré du générateur donné.
+    pub fn fortuitus_cum<R: Rng + ?Sized>(dimensiones: &[usize], aleator: &mut R) -> Self {
+        let magnitudo = dimensiones.iter().product();
+        let elementa = (0..magnitudo).map(|_| T::ex_f64(aleator.random::<f64>())).collect();
+        Self { elementa, dimensiones: dimensiones.to_vec() }
+    }
+
+    /// Tensor fortuitus reproducibilis ex semine.
+/// Tenseur aléatoire reproductible à partir d’une graine.
+    pub fn fortuitus_ex_semine(dimensiones: &[usize], semen: u64) -> Self {
+        Self::fortuitus_cum(dimensiones, &mut ChaCha8Rng::seed_from_u64(semen))
+    }
+
+    /// Forma tensoris.
+/// Forme du tenseur.
+    pub fn forma(&self) -> &[usize] {
+        &self.dimensiones
+    }
+
+    /// Rangus: numerus axium.
+/// Rang : nombre d’axes.
+    pub fn rangus(&self) -> usize {
+        self.dimensiones.len()
+    }
+
+    /// Numerus omnium elementorum.
+/// Nombre total d’éléments.
+    pub fn magnitudo(&self) -> usize {
+        self.elementa.len()
+    }
+
+    /// Converte elementa in alium typum numericum.
+/// Convertit les éléments vers un autre type numérique.
+    pub fn converte<U: Numerus>(&self) -> TensorTND<U> {
+        TensorTND { elementa: self.elementa.iter().map(|x| x.converte()).collect(), dimensiones: self.dimensiones.clone() }
+    }
+
+    /// Gradus (strides) cuiusque axis in elementis.
+/// Pas (strides) de chaque axe, en éléments.
+    pub fn gradus(&self) -> Vec<usize> {
+        gradus_formae(&self.dimensiones)
+    }
+
+    /// Positio in memoria multi-indicis, vel None si extra formam est.
+/// Position dans le tampon d’un multi-indice, ou None s’il sort de la forme.
+    pub fn positio(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.rangus() {
+            return None;
+        }
+        let mut positio = 0;
+        for (&i, &d) in index.iter().zip(&self.dimensiones) {
+            if i >= d {
+                return None;
+            }
+            positio = positio * d + i;
+        }
+        Some(positio)
+    }
+
+    /// Remodula in novam formam eodem numero elementorum (ordo memoriae servatur).
+/// Remodèle vers une nouvelle forme de même nombre d’éléments.
+    pub fn remodula(&self, dimensiones: &[usize]) -> Self {
+        self.try_remodula(dimensiones).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Remodula, errorem reddens si numerus elementorum differt.
+/// Remodèle ; renvoie une erreur si le nombre d’éléments diffère.
+    pub fn try_remodula(&self, dimensiones: &[usize]) -> Result<Self, ErrorTensoris> {
+        if dimensiones.iter().product::<usize>() != self.magnitudo() {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "TensorND::remodula",
+                exspectata: self.dimensiones.clone(),
+                reperta: dimensiones.to_vec(),
+            });
+        }
+        Ok(Self { elementa: self.elementa.clone(), dimensiones: dimensiones.to_vec() })
+    }
+
+    /// Permuta axes: axis i novi tensoris est axis `axes[i]` veteris.
+/// Permute les axes : l’axe i du résultat est l’axe `axes[i]` de l’original.
+    pub fn permuta(&self, axes: &[usize]) -> Self {
+        self.try_permuta(axes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Permuta axes, errorem reddens si `axes` permutatio rangi non est.
+/// Permute les axes ; erreur si `axes` n’est pas une permutation valide.
+    pub fn try_permuta(&self, axes: &[usize]) -> Result<Self, ErrorTensoris> {
+        let mut visi = vec![false; self.rangus()];
+        let valida = axes.len() == self.rangus()
+            && axes.iter().all(|&a| a < visi.len() && !std::mem::replace(&mut visi[a], true));
+        if !valida {
+            return Err(ErrorTensoris::PermutatioInvalida {
+                operatio: "TensorND::permuta",
+                axes: axes.to_vec(),
+            });
+        }
+
+        let dimensiones: Vec<usize> = axes.iter().map(|&a| self.dimensiones[a]).collect();
+        let gradus_veteres = self.gradus();
+        let gradus: Vec<usize> = axes.iter().map(|&a| gradus_veteres[a]).collect();
+
+        // Percurre novum tensorem ordine memoriae, multi-indicem incrementans.
+        // Parcourt le nouveau tenseur dans l’ordre mémoire en incrémentant le multi-indice.
+        let mut elementa = Vec::with_capacity(self.magnitudo());
+        let mut index = vec![0; dimensiones.len()];
+        let mut positio = 0;
+        for _ in 0..self.magnitudo() {
+            elementa.push(self.elementa[positio]);
+            for axis in (0..index.len()).rev() {
+                index[axis] += 1;
+                positio += gradus[axis];
+                if index[axis] < dimensiones[axis] {
+                    break;
+                }
+                positio -= gradus[axis] * index[axis];
+                index[axis] = 0;
+            }
+        }
+
+        Ok(Self { elementa, dimensiones })
+    }
+
+    /// Transpone: ordo axium invertitur.
+/// Transpose : l’ordre des axes est inversé.
+    pub fn transpone(&self) -> Self {
+        let axes: Vec<usize> = (0..self.rangus()).rev().collect();
+        self.permuta(&axes)
+    }
+
+    /// Comprime: omnes axes magnitudinis 1 removentur.
+/// Supprime tous les axes de taille 1.
+    pub fn comprime(&self) -> Self {
+        let dimensiones: Vec<usize> = self.dimensiones.iter().copied().filter(|&d| d != 1).collect();
+        Self { elementa: self.elementa.clone(), dimensiones }
+    }
+
+    /// Comprime unum axem magnitudinis 1.
+/// Supprime un axe de taille 1.
+    pub fn comprime_axem(&self, axis: usize) -> Self {
+        self.try_comprime_axem(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Comprime unum axem, errorem reddens si axis non exstat vel magnitudinem 1 non habet.
+/// Supprime un axe ; erreur si l’axe n’existe pas ou n’est pas de taille 1.
+    pub fn try_comprime_axem(&self, axis: usize) -> Result<Self, ErrorTensoris> {
+        self.verifica_axem("TensorND::comprime_axem", axis, self.rangus())?;
+        if self.dimensiones[axis] != 1 {
+            return Err(ErrorTensoris::FormaeDiscrepantes {
+                operatio: "TensorND::comprime_axem",
+                exspectata: vec![1],
+                reperta: vec![self.dimensiones[axis]],
+            });
+        }
+        let mut dimensiones = self.dimensiones.clone();
+        dimensiones.remove(axis);
+        Ok(Self { elementa: self.elementa.clone(), dimensiones })
+    }
+
+    /// Expande: novum axem magnitudinis 1 in positione `axis` insere.
+/// Insère un nouvel axe de taille 1 à la position `axis`.
+    pub fn expande(&self, axis: usize) -> Self {
+        self.try_expande(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Expande, errorem reddens si `axis` > rangus.
+/// Insère un axe ; erreur si `axis` dépasse le rang.
+    pub fn try_expande(&self, axis: usize) -> Result<Self, ErrorTensoris> {
+        self.verifica_axem("TensorND::expande", axis, self.rangus() + 1)?;
+        let mut dimensiones = self.dimensiones.clone();
+        dimensiones.insert(axis, 1);
+        Ok(Self { elementa: self.elementa.clone(), dimensiones })
+    }
+
+    /// Summa per axem (axis removetur).
+/// Somme le long d’un axe (l’axe est supprimé).
+    pub fn summa_axis(&self, axis: usize) -> Self {
+        self.reduc_axem("TensorND::summa_axis", axis, T::NULLUS, |a, b| a + b)
+    }
+
+    /// Media per axem (axis removetur).
+/// Moyenne le long d’un axe (l’axe est supprimé).
+    pub fn media_axis(&self, axis: usize) -> Self {
+        let mut media = self.summa_axis(axis);
+        let longitudo = T::ex_f64(self.dimensiones[axis] as f64);
+        media.elementa.iter_mut().for_each(|x| *x /= longitudo);
+        media
+    }
+
+    /// Maximum per axem (axis removetur).
+/// Maximum le long d’un axe (l’axe est supprimé).
+    pub fn maximum_axis(&self, axis: usize) -> Self {
+        self.reduc_axem("TensorND::maximum_axis", axis, -T::INFINITUS, T::max)
+    }
+
+    /// Minimum per axem (axis removetur).
+/// Minimum le long d’un axe (l’axe est supprimé).
+    pub fn minimum_axis(&self, axis: usize) -> Self {
+        self.reduc_axem("TensorND::minimum_axis", axis, T::INFINITUS, T::min)
+    }
+
+    /// Reductio generalis per axem.
+/// Réduction générale le long d’un axe.
+    fn reduc_axem(&self, operatio: &'static str, axis: usize, initium: T, f: impl Fn(T, T) -> T) -> Self {
+        self.verifica_axem(operatio, axis, self.rangus()).unwrap_or_else(|e| panic!("{}", e));
+
+        let exterior: usize = self.dimensiones[..axis].iter().product();
+        let longitudo = self.dimensiones[axis];
+        let interior: usize = self.dimensiones[axis + 1..].iter().product();
+
+        let mut elementa = vec![initium; exterior * interior];
+        for e in 0..exterior {
+            for k in 0..longitudo {
+                let fons = &self.elementa[(e * longitudo + k) * interior..(e * longitudo + k + 1) * interior];
+                let meta = &mut elementa[e * interior..(e + 1) * interior];
+                for (m, &x) in meta.iter_mut().zip(fons) {
+                    *m = f(*m, x);
+                }
+            }
+        }
+
+        let mut dimensiones = self.dimensiones.clone();
+        dimensiones.remove(axis);
+        Self { elementa, dimensiones }
+    }
+
+    /// Verifica axem intra limitem esse.
+/// Vérifie que l’axe est dans les bornes.
+    fn verifica_axem(&self, operatio: &'static str, axis: usize, limes: usize) -> Result<(), ErrorTensoris> {
+        if axis >= limes {
+            return Err(ErrorTensoris::AxisInvalidus { operatio, axis, rangus: self.rangus() });
+        }
+        Ok(())
+    }
+
+    /// Verifica rangum ad conversionem in typum fixum.
+/// Vérifie le rang avant conversion vers un type de rang fixe.
+    fn verifica_rangum(&self, operatio: &'static str, rangus: usize) -> Result<(), ErrorTensoris> {
+        if self.rangus() != rangus {
+            return Err(ErrorTensoris::RangusDiscrepans { operatio, exspectatus: rangus, repertus: self.rangus() });
+        }
+        Ok(())
+    }
+}
+
+/// Gradus formae ordine "row-major".
+/// Pas d’une forme en ordre ligne.
+fn gradus_formae(dimensiones: &[usize]) -> Vec<usize> {
+    let mut gradus = vec![1; dimensiones.len()];
+    for axis in (0..dimensiones.len().saturating_sub(1)).rev() {
+        gradus[axis] = gradus[axis + 1] * dimensiones[axis + 1];
+    }
+    gradus
+}
+
+/// Accessus per multi-indicem.
+/// Accès par multi-indice.
+impl<T: Numerus> Index<&[usize]> for TensorTND<T> {
+    type Output = T;
+    fn index(&self, index: &[usize]) -> &T {
+        let positio = self.positio(index).unwrap_or_else(|| panic!("Index {:?} extra formam {:?}.", index, self.dimensiones));
+        &self.elementa[positio]
+    }
+}
+
+impl<T: Numerus> IndexMut<&[usize]> for TensorTND<T> {
+    fn index_mut(&mut self, index: &[usize]) -> &mut T {
+        let positio = self.positio(index).unwrap_or_else(|| panic!("Index {:?} extra formam {:?}.", index, self.dimensiones));
+        &mut self.elementa[positio]
+    }
+}
+
+impl<T: Numerus, const N: usize> Index<[usize; N]> for TensorTND<T> {
+    type Output = T;
+    fn index(&self, index: [usize; N]) -> &T {
+        &self[&index[..]]
+    }
+}
+
+impl<T: Numerus, const N: usize> IndexMut<[usize; N]> for TensorTND<T> {
+    fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+        &mut self[&index[..]]
+    }
+}
+
+/// Conversiones ex typis fixi rangi.
+/// Conversions depuis les types de rang fixe.
+impl<T: Numerus> From<TensorT1D<T>> for TensorTND<T> {
+    fn from(tensor: TensorT1D<T>) -> Self {
+        Self { dimensiones: vec![tensor.magnitudo], elementa: tensor.materia }
+    }
+}
+
+impl<T: Numerus> From<TensorT2D<T>> for TensorTND<T> {
+    fn from(tensor: TensorT2D<T>) -> Self {
+        Self { dimensiones: vec![tensor.ordines, tensor.columnae], elementa: tensor.elementa }
+    }
+}
+
+impl<T: Numerus> From<TensorT3D<T>> for TensorTND<T> {
+    fn from(tensor: TensorT3D<T>) -> Self {
+        Self {
+            dimensiones: vec![tensor.profunditas, tensor.altitudo, tensor.latitudo],
+            elementa: tensor.elementa,
+        }
+    }
+}
+
+/// Conversiones in typos fixi rangi (rangus convenire debet).
+/// Conversions vers les types de rang fixe (le rang doit correspondre).
+impl<T: Numerus> TryFrom<TensorTND<T>> for TensorT1D<T> {
+    type Error = ErrorTensoris;
+    fn try_from(tensor: TensorTND<T>) -> Result<Self, ErrorTensoris> {
+        tensor.verifica_rangum("Tensor1D::try_from", 1)?;
+        Ok(TensorT1D::ex_vec(tensor.elementa))
+    }
+}
+
+impl<T: Numerus> TryFrom<TensorTND<T>> for TensorT2D<T> {
+    type Error = ErrorTensoris;
+    fn try_from(tensor: TensorTND<T>) -> Result<Self, ErrorTensoris> {
+        tensor.verifica_rangum("Tensor2D::try_from", 2)?;
+        Ok(TensorT2D::ex_elementis(tensor.dimensiones[0], tensor.dimensiones[1], tensor.elementa))
+    }
+}
+
+impl<T: Numerus> TryFrom<TensorTND<T>> for TensorT3D<T> {
+    type Error = ErrorTensoris;
+    fn try_from(tensor: TensorTND<T>) -> Result<Self, ErrorTensoris> {
+        tensor.verifica_rangum("Tensor3D::try_from", 3)?;
+        let [profunditas, altitudo, latitudo] = [tensor.dimensiones[0], tensor.dimensiones[1], tensor.dimensiones[2]];
+        Ok(TensorT3D::ex_elementis(profunditas, altitudo, latitudo, tensor.elementa))
+    }
+}
+
+/// Forma legibilis: forma, deinde ultimus axis per lineas.
+/// Affichage lisible : la forme, puis le dernier axe ligne par ligne.
+impl<T: Numerus> fmt::Display for TensorTND<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Forma {:?}:", self.dimensiones)?;
+        let latitudo = self.dimensiones.last().copied().unwrap_or(1).max(1);
+        for ordo in self.elementa.chunks(latitudo) {
+            writeln!(
+                f,
+                "{}",
+                ordo.iter()
+                    .map(|v| format!("{:.4}", v))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::super::{Tensor2D, Tensor3D};
+    use super::*;
+
+    /// Batch imaginum: [exempla, canales, altitudo, latitudo].
+    /// Lot d’images : [exemples, canaux, hauteur, largeur].
+    fn imagines() -> TensorND {
+        TensorND::ex_elementis(&[2, 1, 2, 3], (0..12).map(|x| x as f64).collect())
+    }
+
+    /// Probat rangum, gradus et indicationem.
+    /// Teste le rang, les pas et l’indexation.
+    #[test]
+    fn probatio_gradus_et_index() {
+        let t = imagines();
+        assert_eq!(t.rangus(), 4);
+        assert_eq!(t.gradus(), vec![6, 6, 3, 1]);
+        assert_eq!(t[[1, 0, 1, 2]], 11.0);
+    }
+
+    /// Probat compressionem axium unitariorum et expansionem inversam.
+    /// Teste la compression des axes unitaires et l’expansion inverse.
+    #[test]
+    fn probatio_comprime_et_expande() {
+        let t = imagines();
+        let c = t.comprime();
+        assert_eq!(c.forma(), &[2, 2, 3]);
+        assert_eq!(c.expande(1), t);
+        assert!(matches!(t.try_comprime_axem(0), Err(ErrorTensoris::FormaeDiscrepantes { .. })));
+    }
+
+    /// Probat permutationem axium et permutationes invalidas reici.
+    /// Teste la permutation d’axes et le rejet des permutations invalides.
+    #[test]
+    fn probatio_permuta() {
+        let c = imagines().comprime();
+        let p = c.permuta(&[2, 0, 1]);
+        assert_eq!(p.forma(), &[3, 2, 2]);
+        assert_eq!(p[[2, 1, 0]], c[[1, 0, 2]]);
+        assert!(matches!(c.try_permuta(&[0, 0, 1]), Err(ErrorTensoris::PermutatioInvalida { .. })));
+    }
+
+    /// Probat reductiones per axem et remodulationem invalidam reici.
+    /// Teste les réductions par axe et le rejet d’un remodelage invalide.
+    #[test]
+    fn probatio_reductiones_axis() {
+        let c = imagines().comprime();
+        assert_eq!(c.summa_axis(0).elementa, vec![6.0, 8.0, 10.0, 12.0, 14.0, 16.0]);
+        assert_eq!(c.maximum_axis(2).elementa, vec![2.0, 5.0, 8.0, 11.0]);
+        assert_eq!(c.media_axis(1).forma(), &[2, 3]);
+        assert!(c.try_remodula(&[5, 2]).is_err());
+    }
+
+    /// Probat conversiones cum typis fixi rangi.
+    /// Teste les conversions avec les types de rang fixe.
+    #[test]
+    fn probatio_conversiones() {
+        let matrix = Tensor2D::ex_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let nd = TensorND::from(matrix.clone());
+        assert_eq!(Tensor2D::try_from(nd.transpone()).unwrap(), matrix.transpone());
+        let c = imagines().comprime();
+        assert_eq!(Tensor3D::try_from(c.clone()).unwrap().planum(1)[(0, 2)], 8.0);
+        assert!(matches!(Tensor2D::try_from(c), Err(ErrorTensoris::RangusDiscrepans { .. })));
+    }
+
+    /// Probat generatorem datum et semen idem reddere.
+    /// Teste qu’un générateur donné et la graine donnent le même tenseur.
+    #[test]
+    fn probatio_semen() {
+        let mut aleator = ChaCha8Rng::seed_from_u64(3);
+        let primus = TensorND::fortuitus_cum(&[2, 2], &mut aleator);
+        assert_eq!(primus, TensorND::fortuitus_ex_semine(&[2, 2], 3));
+    }
+
+    /// Probat archivum cum elementis et dimensionibus discrepantibus errorem reddere.
+    /// Teste qu’un fichier aux éléments et dimensions incohérents donne une erreur.
+    #[test]
+    fn probatio_archivum_invalidum() {
+        assert!(serde_json::from_str::<TensorND>(r#"{"elementa":[1.0,2.0,3.0],"dimensiones":[2,2]}"#).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//
+// NUMERUS FLUITANS
+// ----------------
+// Trait commun des types d’éléments (f32, f64) des tenseurs et du réseau.
+//
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Typus elementorum tensoris: numerus fluitans (f32 vel f64).
+/// Type d’élément d’un tenseur : nombre flottant (f32 ou f64).
+pub trait Numerus:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + Product
+    + for<'a> Sum<&'a Self>
+    + Serialize
+    + DeserializeOwned
+{
+    /// Nomen typi ("f32" vel "f64"), in serializatione scriptum.
+/// Nom du type ("f32" ou "f64"), écrit lors de la sérialisation.
+    const NOMEN: &'static str;
+
+    /// Zero.
+/// Zéro.
+    const NULLUS: Self;
+
+    /// Unitas.
+/// Un.
+    const UNUS: Self;
+
+    /// Infinitum positivum.
+/// Infini positif.
+    const INFINITUS: Self;
+
+    /// Epsilon machinae.
+/// Epsilon machine.
+    const EPSILON: Self;
+
+    /// Conversio ex f64 (cum rotundatione si opus est).
+/// Conversion depuis f64 (avec arrondi si nécessaire).
+    fn ex_f64(x: f64) -> Self;
+
+    /// Conversio in f64.
+/// Conversion vers f64.
+    fn in_f64(self) -> f64;
+
+    /// Conversio in alium typum numericum.
+/// Conversion vers un autre type numérique.
+    fn converte<U: Numerus>(self) -> U {
+        U::ex_f64(self.in_f64())
+    }
+
+    // Functiones mathematicae, sicut methodi typorum primitivorum.
+    // Fonctions mathématiques, comme les méthodes des types primitifs.
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn signum(self) -> Self;
+    fn max(self, alius: Self) -> Self;
+    fn min(self, alius: Self) -> Self;
+    fn total_cmp(&self, alius: &Self) -> Ordering;
+}
+
+/// Implementatio per methodos propriosque typi primitivi.
+/// Implémentation via les méthodes inhérentes du type primitif.
+macro_rules! numerus_primitivus {
+    ($typus:ident) => {
+        impl Numerus for $typus {
+            const NOMEN: &'static str = stringify!($typus);
+            const NULLUS: Self = 0.0;
+            const UNUS: Self = 1.0;
+            const INFINITUS: Self = $typus::INFINITY;
+            const EPSILON: Self = $typus::EPSILON;
+
+            fn ex_f64(x: f64) -> Self {
+                x as $typus
+            }
+
+            fn in_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> Self {
+                $typus::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $typus::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                $typus::exp(self)
+            }
+
+            fn exp_m1(self) -> Self {
+                $typus::exp_m1(self)
+            }
+
+            fn ln(self) -> Self {
+                $typus::ln(self)
+            }
+
+            fn ln_1p(self) -> Self {
+                $typus::ln_1p(self)
+            }
+
+            fn tanh(self) -> Self {
+                $typus::tanh(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $typus::powi(self, n)
+            }
+
+            fn signum(self) -> Self {
+                $typus::signum(self)
+            }
+
+            fn max(self, alius: Self) -> Self {
+                $typus::max(self, alius)
+            }
+
+            fn min(self, alius: Self) -> Self {
+                $typus::min(self, alius)
+            }
+
+            fn total_cmp(&self, alius: &Self) -> Ordering {
+                $typus::total_cmp(self, alius)
+            }
+        }
+    };
+}
+
+numerus_primitivus!(f32);
+numerus_primitivus!(f64);
@@ -0,0 +1,402 @@
+//
+// OPERATORES ARITHMETICI
+// ----------------------
+// Opérateurs +, −, ×, ÷ élément par élément, avec diffusion (broadcasting) à la NumPy.
+//
+
+use super::{ErrorTensoris, Numerus, TensorT1D, TensorT2D};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Magnitudo communis duarum dimensionum secundum regulas diffusionis (aequales, vel altera 1).
+/// Taille commune de deux dimensions selon les règles de diffusion (égales, ou l’une vaut 1).
+fn dimensio_diffusa(a: usize, b: usize) -> Option<usize> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None,
+    }
+}
+
+impl<T: Numerus> TensorT2D<T> {
+    /// Operatio elementaris cum diffusione: dimensiones aequales vel 1 (sicut in NumPy).
+/// Opération élément par élément avec diffusion : dimensions égales ou valant 1 (comme NumPy).
+    pub fn try_operatio_diffusa(
+        &self,
+        alius: &TensorT2D<T>,
+        operatio: &'static str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<TensorT2D<T>, ErrorTensoris> {
+        let error = || ErrorTensoris::FormaeDiscrepantes {
+            operatio,
+            exspectata: self.forma().to_vec(),
+            reperta: alius.forma().to_vec(),
+        };
+        let ordines = dimensio_diffusa(self.ordines, alius.ordines).ok_or_else(error)?;
+        let columnae = dimensio_diffusa(self.columnae, alius.columnae).ok_or_else(error)?;
+
+        // Via celeris si formae aequales sunt.
+        // Chemin rapide si les formes sont identiques.
+        if self.forma() == alius.forma() {
+            let elementa = self.elementa.iter().zip(&alius.elementa).map(|(&a, &b)| f(a, b)).collect();
+            return Ok(TensorT2D::ex_elementis(ordines, columnae, elementa));
+        }
+
+        let mut elementa = Vec::with_capacity(ordines * columnae);
+        for i in 0..ordines {
+            let (ia, ib) = (i.min(self.ordines - 1), i.min(alius.ordines - 1));
+            for j in 0..columnae {
+                let (ja, jb) = (j.min(self.columnae - 1), j.min(alius.columnae - 1));
+                elementa.push(f(self[(ia, ja)], alius[(ib, jb)]));
+            }
+        }
+
+        Ok(TensorT2D::ex_elementis(ordines, columnae, elementa))
+    }
+
+    /// Operatio elementaris cum diffusione; panicum si formae non conveniunt.
+/// Opération avec diffusion ; panique si les formes sont incompatibles.
+    pub fn operatio_diffusa(
+        &self,
+        alius: &TensorT2D<T>,
+        operatio: &'static str,
+        f: impl Fn(T, T) -> T,
+    ) -> TensorT2D<T> {
+        self.try_operatio_diffusa(alius, operatio, f).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Operatio in loco: alius ad formam huius tensoris diffunditur.
+/// Opération en place : l’autre tenseur est diffusé vers la forme de celui-ci.
+    fn operatio_in_loco(&mut self, alius: &TensorT2D<T>, operatio: &'static str, f: impl Fn(T, T) -> T) {
+        let resultatum = self.operatio_diffusa(alius, operatio, f);
+        assert_eq!(
+            resultatum.forma(),
+            self.forma(),
+            "{}: forma {:?} ad {:?} diffundi non potest.",
+            operatio,
+            alius.forma(),
+            self.forma()
+        );
+        *self = resultatum;
+    }
+}
+
+impl<T: Numerus> TensorT1D<T> {
+    /// Vector ut ordo unus [1 x n], ad diffusionem cum Tensor2D.
+/// Le vecteur vu comme une ligne [1 x n], pour la diffusion avec un Tensor2D.
+    pub fn ut_ordo(&self) -> TensorT2D<T> {
+        TensorT2D::ex_elementis(1, self.magnitudo, self.materia.clone())
+    }
+
+    /// Operatio elementaris cum diffusione (magnitudines aequales, vel altera 1).
+/// Opération élément par élément avec diffusion (tailles égales, ou l’une vaut 1).
+    pub fn try_operatio_diffusa(
+        &self,
+        alius: &TensorT1D<T>,
+        operatio: &'static str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<TensorT1D<T>, ErrorTensoris> {
+        let magnitudo = dimensio_diffusa(self.magnitudo, alius.magnitudo).ok_or_else(|| {
+            ErrorTensoris::FormaeDiscrepantes {
+                operatio,
+                exspectata: vec![self.magnitudo],
+                reperta: vec![alius.magnitudo],
+            }
+        })?;
+
+        let materia = (0..magnitudo)
+            .map(|i| f(self.materia[i.min(self.magnitudo - 1)], alius.materia[i.min(alius.magnitudo - 1)]))
+            .collect();
+        Ok(TensorT1D::ex_vec(materia))
+    }
+
+    /// Operatio elementaris cum diffusione; panicum si magnitudines non conveniunt.
+/// Opération avec diffusion ; panique si les tailles sont incompatibles.
+    pub fn operatio_diffusa(
+        &self,
+        alius: &TensorT1D<T>,
+        operatio: &'static str,
+        f: impl Fn(T, T) -> T,
+    ) -> TensorT1D<T> {
+        self.try_operatio_diffusa(alius, operatio, f).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Ex implementatione per referentias, deriva formas cum valoribus possessis.
+/// À partir de l’implémentation par références, dérive les variantes par valeur.
+macro_rules! per_valorem {
+    ($Trait:ident, $methodus:ident, $A:ty, $B:ty, $Exitus:ty) => {
+        impl<T: Numerus> $Trait<$B> for $A {
+            type Output = $Exitus;
+            fn $methodus(self, alius: $B) -> $Exitus {
+                (&self).$methodus(&alius)
+            }
+        }
+
+        impl<T: Numerus> $Trait<&$B> for $A {
+            type Output = $Exitus;
+            fn $methodus(self, alius: &$B) -> $Exitus {
+                (&self).$methodus(alius)
+            }
+        }
+
+        impl<T: Numerus> $Trait<$B> for &$A {
+            type Output = $Exitus;
+            fn $methodus(self, alius: $B) -> $Exitus {
+                self.$methodus(&alius)
+            }
+        }
+    };
+}
+
+/// Scalar a sinistra (e.g. `2.0 * &tensor`): pro quoque typo primitivo separatim implementatur.
+/// Scalaire à gauche (p. ex. `2.0 * &tenseur`) : implémenté séparément pour chaque type primitif.
+macro_rules! scalar_sinister {
+    ($Trait:ident, $methodus:ident, $op:tt, $($typus:ty),*) => {
+        $(
+            impl $Trait<&TensorT2D<$typus>> for $typus {
+                type Output = TensorT2D<$typus>;
+                fn $methodus(self, tensor: &TensorT2D<$typus>) -> TensorT2D<$typus> {
+                    tensor.map(|b| self $op b)
+                }
+            }
+
+            impl $Trait<TensorT2D<$typus>> for $typus {
+                type Output = TensorT2D<$typus>;
+                fn $methodus(self, tensor: TensorT2D<$typus>) -> TensorT2D<$typus> {
+                    self.$methodus(&tensor)
+                }
+            }
+
+            impl $Trait<&TensorT1D<$typus>> for $typus {
+                type Output = TensorT1D<$typus>;
+                fn $methodus(self, tensor: &TensorT1D<$typus>) -> TensorT1D<$typus> {
+                    TensorT1D::ex_vec(tensor.materia.iter().map(|&b| self $op b).collect())
+                }
+            }
+
+            impl $Trait<TensorT1D<$typus>> for $typus {
+                type Output = TensorT1D<$typus>;
+                fn $methodus(self, tensor: TensorT1D<$typus>) -> TensorT1D<$typus> {
+                    self.$methodus(&tensor)
+                }
+            }
+        )*
+    };
+}
+
+/// Operatores binarii (+, −, ×, ÷) inter tensores et scalares.
+/// Opérateurs binaires (+, −, ×, ÷) entre tenseurs et scalaires.
+macro_rules! operatio_binaria {
+    ($Trait:ident, $methodus:ident, $TraitLoco:ident, $methodus_loco:ident, $op:tt) => {
+        // Tensor2D ∘ Tensor2D
+        impl<T: Numerus> $Trait<&TensorT2D<T>> for &TensorT2D<T> {
+            type Output = TensorT2D<T>;
+            fn $methodus(self, alius: &TensorT2D<T>) -> TensorT2D<T> {
+                self.operatio_diffusa(alius, concat!("Tensor2D::", stringify!($methodus)), |a, b| a $op b)
+            }
+        }
+        per_valorem!($Trait, $methodus, TensorT2D<T>, TensorT2D<T>, TensorT2D<T>);
+
+        // Tensor2D ∘ Tensor1D: vector ut ordo ad omnes ordines diffunditur.
+        impl<T: Numerus> $Trait<&TensorT1D<T>> for &TensorT2D<T> {
+            type Output = TensorT2D<T>;
+            fn $methodus(self, alius: &TensorT1D<T>) -> TensorT2D<T> {
+                self.operatio_diffusa(&alius.ut_ordo(), concat!("Tensor2D::", stringify!($methodus)), |a, b| a $op b)
+            }
+        }
+        per_valorem!($Trait, $methodus, TensorT2D<T>, TensorT1D<T>, TensorT2D<T>);
+
+        // Tensor1D ∘ Tensor2D
+        impl<T: Numerus> $Trait<&TensorT2D<T>> for &TensorT1D<T> {
+            type Output = TensorT2D<T>;
+            fn $methodus(self, alius: &TensorT2D<T>) -> TensorT2D<T> {
+                self.ut_ordo().operatio_diffusa(alius, concat!("Tensor2D::", stringify!($methodus)), |a, b| a $op b)
+            }
+        }
+        per_valorem!($Trait, $methodus, TensorT1D<T>, TensorT2D<T>, TensorT2D<T>);
+
+        // Tensor1D ∘ Tensor1D
+        impl<T: Numerus> $Trait<&TensorT1D<T>> for &TensorT1D<T> {
+            type Output = TensorT1D<T>;
+            fn $methodus(self, alius: &TensorT1D<T>) -> TensorT1D<T> {
+                self.operatio_diffusa(alius, concat!("Tensor1D::", stringify!($methodus)), |a, b| a $op b)
+            }
+        }
+        per_valorem!($Trait, $methodus, TensorT1D<T>, TensorT1D<T>, TensorT1D<T>);
+
+        // Tensor ∘ scalar et scalar ∘ tensor
+        impl<T: Numerus> $Trait<T> for &TensorT2D<T> {
+            type Output = TensorT2D<T>;
+            fn $methodus(self, scala: T) -> TensorT2D<T> {
+                self.map(|a| a $op scala)
+            }
+        }
+
+        impl<T: Numerus> $Trait<T> for TensorT2D<T> {
+            type Output = TensorT2D<T>;
+            fn $methodus(self, scala: T) -> TensorT2D<T> {
+                (&self).$methodus(scala)
+            }
+        }
+
+        impl<T: Numerus> $Trait<T> for &TensorT1D<T> {
+            type Output = TensorT1D<T>;
+            fn $methodus(self, scala: T) -> TensorT1D<T> {
+                TensorT1D::ex_vec(self.materia.iter().map(|&a| a $op scala).collect())
+            }
+        }
+
+        impl<T: Numerus> $Trait<T> for TensorT1D<T> {
+            type Output = TensorT1D<T>;
+            fn $methodus(self, scala: T) -> TensorT1D<T> {
+                (&self).$methodus(scala)
+            }
+        }
+
+        scalar_sinister!($Trait, $methodus, $op, f32, f64);
+
+        // Formae in loco (+=, −=, ×=, ÷=): forma sinistri non mutatur.
+        impl<T: Numerus> $TraitLoco<&TensorT2D<T>> for TensorT2D<T> {
+            fn $methodus_loco(&mut self, alius: &TensorT2D<T>) {
+                self.operatio_in_loco(alius, concat!("Tensor2D::", stringify!($methodus_loco)), |a, b| a $op b);
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<TensorT2D<T>> for TensorT2D<T> {
+            fn $methodus_loco(&mut self, alius: TensorT2D<T>) {
+                self.$methodus_loco(&alius);
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<&TensorT1D<T>> for TensorT2D<T> {
+            fn $methodus_loco(&mut self, alius: &TensorT1D<T>) {
+                self.operatio_in_loco(&alius.ut_ordo(), concat!("Tensor2D::", stringify!($methodus_loco)), |a, b| a $op b);
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<TensorT1D<T>> for TensorT2D<T> {
+            fn $methodus_loco(&mut self, alius: TensorT1D<T>) {
+                self.$methodus_loco(&alius);
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<T> for TensorT2D<T> {
+            fn $methodus_loco(&mut self, scala: T) {
+                for a in &mut self.elementa {
+                    *a = *a $op scala;
+                }
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<&TensorT1D<T>> for TensorT1D<T> {
+            fn $methodus_loco(&mut self, alius: &TensorT1D<T>) {
+                let resultatum = self.operatio_diffusa(alius, concat!("Tensor1D::", stringify!($methodus_loco)), |a, b| a $op b);
+                assert_eq!(resultatum.magnitudo, self.magnitudo, "Magnitudo {} ad {} diffundi non potest.", alius.magnitudo, self.magnitudo);
+                *self = resultatum;
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<TensorT1D<T>> for TensorT1D<T> {
+            fn $methodus_loco(&mut self, alius: TensorT1D<T>) {
+                self.$methodus_loco(&alius);
+            }
+        }
+
+        impl<T: Numerus> $TraitLoco<T> for TensorT1D<T> {
+            fn $methodus_loco(&mut self, scala: T) {
+                for a in &mut self.materia {
+                    *a = *a $op scala;
+                }
+            }
+        }
+    };
+}
+
+operatio_binaria!(Add, add, AddAssign, add_assign, +);
+operatio_binaria!(Sub, sub, SubAssign, sub_assign, -);
+operatio_binaria!(Mul, mul, MulAssign, mul_assign, *);
+operatio_binaria!(Div, div, DivAssign, div_assign, /);
+
+impl<T: Numerus> Neg for &TensorT2D<T> {
+    type Output = TensorT2D<T>;
+    fn neg(self) -> TensorT2D<T> {
+        self.map(|a| -a)
+    }
+}
+
+impl<T: Numerus> Neg for TensorT2D<T> {
+    type Output = TensorT2D<T>;
+    fn neg(self) -> TensorT2D<T> {
+        -&self
+    }
+}
+
+impl<T: Numerus> Neg for &TensorT1D<T> {
+    type Output = TensorT1D<T>;
+    fn neg(self) -> TensorT1D<T> {
+        TensorT1D::ex_vec(self.materia.iter().map(|&a| -a).collect())
+    }
+}
+
+impl<T: Numerus> Neg for TensorT1D<T> {
+    type Output = TensorT1D<T>;
+    fn neg(self) -> TensorT1D<T> {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::super::{Tensor1D, Tensor2D};
+
+    fn a() -> Tensor2D {
+        Tensor2D::ex_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+    }
+
+    fn bias() -> Tensor1D {
+        Tensor1D::ex_vec(vec![10.0, 20.0])
+    }
+
+    /// Probat operatores elementares inter tensores eiusdem formae et cum scalari.
+    /// Teste les opérateurs élément par élément entre tenseurs de même forme et avec un scalaire.
+    #[test]
+    fn probatio_operatores_elementares() {
+        let (a, b) = (a(), Tensor2D::ex_vec(vec![vec![2.0, 2.0], vec![1.0, 4.0]]));
+        assert_eq!((&a + &b).materia(), vec![vec![3.0, 4.0], vec![4.0, 8.0]]);
+        assert_eq!((&a * &b).materia(), vec![vec![2.0, 4.0], vec![3.0, 16.0]]);
+        assert_eq!((&a / &b).materia(), vec![vec![0.5, 1.0], vec![3.0, 1.0]]);
+        assert_eq!((-&a).materia(), vec![vec![-1.0, -2.0], vec![-3.0, -4.0]]);
+        assert_eq!((2.0 - &a).materia(), vec![vec![1.0, 0.0], vec![-1.0, -2.0]]);
+    }
+
+    /// Probat vectorem ad omnes ordines, columnam ad omnes columnas diffundi.
+    /// Teste qu’un vecteur est diffusé sur chaque ligne, une colonne sur chaque colonne.
+    #[test]
+    fn probatio_diffusio() {
+        let a = a();
+        assert_eq!((&a + &bias()).materia(), vec![vec![11.0, 22.0], vec![13.0, 24.0]]);
+        assert_eq!((&a - bias().transpone()).materia(), vec![vec![-9.0, -8.0], vec![-17.0, -16.0]]);
+        let v = &bias() - &Tensor1D::ex_vec(vec![1.0]);
+        assert_eq!(v.materia, vec![9.0, 19.0]);
+    }
+
+    /// Probat operatores assignationis cum diffusione.
+    /// Teste les opérateurs d’affectation avec diffusion.
+    #[test]
+    fn probatio_operatores_assignationis() {
+        let mut c = a();
+        c += &bias();
+        c *= 0.5;
+        assert_eq!(c.materia(), vec![vec![5.5, 11.0], vec![6.5, 12.0]]);
+    }
+
+    /// Probat formas non diffundibiles errorem (vel panicum) reddere.
+    /// Teste que des formes non diffusables donnent une erreur (ou une panique).
+    #[test]
+    fn probatio_diffusio_incongrua() {
+        let (a, tres) = (a(), Tensor2D::ex_vec(vec![vec![1.0, 2.0, 3.0]]));
+        assert!(a.try_operatio_diffusa(&tres, "add", |x, y| x + y).is_err());
+        assert!(std::panic::catch_unwind(|| &a + &tres).is_err());
+    }
+}
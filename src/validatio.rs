@@ -0,0 +1,192 @@
+//
+// VALIDATIO
+// ---------
+// Évaluation sur un ensemble de validation à chaque époque, arrêt anticipé et restauration des meilleurs poids.
+//
+
+use minitensor::{Numerus, TensorT3D};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Serialize, Deserialize};
+
+/// Exempla validationis pro `Neuronatus::disciplina_validata`.
+/// Exemples de validation pour `Neuronatus::disciplina_validata`.
+#[derive(Debug, Clone, Copy)]
+pub enum Validatio<'a, T = f64> {
+    /// Copia validationis separata, eiusdem formae ac exempla disciplinae.
+/// Ensemble de validation séparé, de même forme que les exemples d’entraînement.
+    Exempla { intratae: &'a TensorT3D<T>, exspectatae: &'a TensorT3D<T> },
+
+    /// Pars exemplorum disciplinae (e.g. 0.2) fortuito electa seponitur, ex generatore retis si semen datum est.
+/// Une fraction des exemples d’entraînement (p. ex. 0.2), tirée au hasard, est mise de côté, avec le générateur du réseau si une graine est fixée.
+    Fractio(f64),
+}
+
+/// Par (intratae, exspectatae) exemplorum [exempla][1][magnitudo].
+/// Paire (entrées, attendues) d’exemples [exemples][1][taille].
+pub type ParExemplorum<T = f64> = (TensorT3D<T>, TensorT3D<T>);
+
+/// Mensura validationis qua epocha optima eligitur.
+/// Mesure de validation qui désigne la meilleure époque.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Criterium {
+    /// Perditio validationis minima (sine poena regularizationis).
+/// Perte de validation minimale (sans pénalité de régularisation).
+    #[default]
+    Perditio,
+    /// Accuratio validationis maxima.
+/// Exactitude de validation maximale.
+    Accuratio,
+}
+
+/// Cessatio praecox: disciplina sistitur post `patientia` epochas sine progressu validationis
+/// maiore quam `tolerantia`; pondera epochae optimae in fine restituuntur.
+/// Arrêt anticipé : l’entraînement s’arrête après `patientia` époques sans progrès de validation
+/// supérieur à `tolerantia` ; les poids de la meilleure époque sont restaurés à la fin.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Cessatio {
+    pub patientia: Option<usize>,  // None = toutes les époques sont effectuées
+    pub tolerantia: f64,           // Amélioration minimale (absolue) comptée comme progrès
+    pub criterium: Criterium,      // Perte ou exactitude de validation
+    pub restitue_optima: bool,     // Restaurer les poids de la meilleure époque
+}
+
+impl Default for Cessatio {
+    fn default() -> Self {
+        Cessatio { patientia: None, tolerantia: 0.0, criterium: Criterium::Perditio, restitue_optima: true }
+    }
+}
+
+impl Cessatio {
+    /// Cessatio post `patientia` epochas sine diminutione perditionis validationis.
+/// Arrêt après `patientia` époques sans baisse de la perte de validation.
+    pub fn patientia(patientia: usize) -> Self {
+        Cessatio { patientia: Some(patientia), ..Default::default() }
+    }
+
+    /// Verum si mensura epochae optimum hactenus superat (plus quam tolerantia).
+/// Vrai si la mesure de l’époque dépasse le meilleur résultat jusqu’ici (de plus que la tolérance).
+    pub fn melior(&self, mensura: &MensuraEpochae, optima: Option<&MensuraEpochae>) -> bool {
+        let Some(optima) = optima else {
+            return true;
+        };
+        match self.criterium {
+            Criterium::Perditio => mensura.perditio_validationis < optima.perditio_validationis - self.tolerantia,
+            Criterium::Accuratio => mensura.accuratio_validationis > optima.accuratio_validationis + self.tolerantia,
+        }
+    }
+}
+
+/// Mensurae unius epochae.
+/// Mesures d’une époque.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct MensuraEpochae {
+    pub epocha: usize,                // Indice de l’époque (à partir de 0)
+    pub perditio: f64,                // Perte d’entraînement
+    pub perditio_validationis: f64,   // Perte de validation (sans pénalité L1/L2)
+    pub accuratio_validationis: f64,  // Exactitude de validation, dans [0, 1]
+    pub celeritas: f64,               // Taux d’apprentissage de l’époque
+}
+
+/// Historia disciplinae validatae.
+/// Historique d’un entraînement avec validation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct HistoriaDisciplinae {
+    pub epochae: Vec<MensuraEpochae>,  // Mesures de chaque époque effectuée
+    pub optima: Option<usize>,         // Indice (dans `epochae`) de la meilleure époque
+    pub interrupta: bool,              // Vrai si l’arrêt anticipé a interrompu l’entraînement
+    pub restituta: bool,               // Vrai si les poids de la meilleure époque ont été restaurés
+}
+
+impl HistoriaDisciplinae {
+    /// Mensurae epochae optimae.
+/// Mesures de la meilleure époque.
+    pub fn mensura_optima(&self) -> Option<&MensuraEpochae> {
+        self.optima.map(|index| &self.epochae[index])
+    }
+}
+
+/// Divide exempla [exempla][1][magnitudo]: post permutationem fortuitam ex `aleator`, pars `fractio`
+/// (saltem unum exemplum) validationi seponitur, ne archiva ordinata validationem unius classis reddant.
+/// Reddit ((intratae, exspectatae) disciplinae, (intratae, exspectatae) validationis).
+/// Divise les exemples : après une permutation aléatoire tirée de `aleator`, la fraction `fractio` (au moins
+/// un exemple) est réservée à la validation, pour qu’un fichier trié ne donne pas une validation d’une seule classe.
+/// Renvoie ((entrées, attendues) d’entraînement, (entrées, attendues) de validation).
+pub fn divide_exempla<T: Numerus, R: Rng + ?Sized>(
+    intratae: &TensorT3D<T>,
+    exspectatae: &TensorT3D<T>,
+    fractio: f64,
+    aleator: &mut R,
+) -> (ParExemplorum<T>, ParExemplorum<T>) {
+    assert_eq!(intratae.profunditas, exspectatae.profunditas, "Batch amplitudo debet convenire.");
+    assert!(fractio > 0.0 && fractio < 1.0, "Fractio validationis in (0, 1) esse debet.");
+
+    let n = intratae.profunditas;
+    let validatio = ((n as f64 * fractio).round() as usize).max(1);
+    assert!(validatio < n, "Nulla exempla disciplinae post divisionem manent.");
+
+    let mut indices: Vec<usize> = (0..n).collect();
+    indices.shuffle(aleator);
+    let (disciplinae, validationis) = indices.split_at(n - validatio);
+    (
+        (elige(intratae, disciplinae), elige(exspectatae, disciplinae)),
+        (elige(intratae, validationis), elige(exspectatae, validationis)),
+    )
+}
+
+/// Elige plana `indices` tensoris, hoc ordine.
+/// Sélectionne les plans `indices` du tenseur, dans cet ordre.
+fn elige<T: Numerus>(data: &TensorT3D<T>, indices: &[usize]) -> TensorT3D<T> {
+    let planum = data.altitudo * data.latitudo;
+    let elementa = indices.iter().flat_map(|&k| &data.elementa[k * planum..(k + 1) * planum]).copied().collect();
+    TensorT3D::ex_elementis(indices.len(), data.altitudo, data.latitudo, elementa)
+}
+
+#[cfg(test)]
+mod probationes {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Archivum per classes ordinatum: exspectatum = 10 · intratum, classes 0 deinde 1.
+    /// Fichier trié par classe : attendue = 10 · entrée, classes 0 puis 1.
+    fn exempla() -> (TensorT3D<f64>, TensorT3D<f64>) {
+        let intratae = TensorT3D::ex_elementis(10, 1, 1, (0..10).map(f64::from).collect());
+        let exspectatae = TensorT3D::ex_elementis(10, 1, 1, (0..10).map(|k| f64::from(10 * k)).collect());
+        (intratae, exspectatae)
+    }
+
+    /// Probat divisionem exempla permutare et paria (intrata, exspectata) servare.
+    /// Teste que la division permute les exemples et conserve les paires (entrée, attendue).
+    #[test]
+    fn probatio_divide_exempla() {
+        let (intratae, exspectatae) = exempla();
+        let mut aleator = ChaCha8Rng::seed_from_u64(7);
+        let ((di, de), (vi, ve)) = divide_exempla(&intratae, &exspectatae, 0.4, &mut aleator);
+        assert_eq!((di.profunditas, vi.profunditas), (6, 4));
+
+        let mut omnia: Vec<f64> = di.elementa.iter().chain(&vi.elementa).copied().collect();
+        omnia.sort_by(f64::total_cmp);
+        assert_eq!(omnia, intratae.elementa);
+        assert!(di.elementa.iter().zip(&de.elementa).chain(vi.elementa.iter().zip(&ve.elementa)).all(|(x, t)| *t == 10.0 * x));
+    }
+
+    /// Probat divisionem permixtam utramque classem in validatione ponere.
+    /// Teste que la division mélangée place les deux classes dans la validation.
+    #[test]
+    fn probatio_divide_exempla_permixta() {
+        let (intratae, exspectatae) = exempla();
+        let ((_, _), (vi, _)) = divide_exempla(&intratae, &exspectatae, 0.4, &mut ChaCha8Rng::seed_from_u64(7));
+        assert!(vi.elementa.iter().any(|&x| x < 5.0) && vi.elementa.iter().any(|&x| x >= 5.0));
+    }
+
+    /// Probat idem semen eandem divisionem reddere.
+    /// Teste que la même graine donne la même division.
+    #[test]
+    fn probatio_divide_exempla_semen() {
+        let (intratae, exspectatae) = exempla();
+        let ((_, _), (primum, _)) = divide_exempla(&intratae, &exspectatae, 0.4, &mut ChaCha8Rng::seed_from_u64(7));
+        let ((_, _), (iterum, _)) = divide_exempla(&intratae, &exspectatae, 0.4, &mut ChaCha8Rng::seed_from_u64(7));
+        assert_eq!(iterum, primum);
+    }
+}
@@ -0,0 +1,155 @@
+//
+// VERIFICATIO GRADIENTUM
+// ----------------------
+// Compare les gradients analytiques de la rétropropagation aux différences finies centrées.
+//
+
+use minitensor::{Numerus, TensorT1D, TensorT2D};
+use crate::neuronatus::{Modus, NeuronatusT};
+use std::fmt;
+
+/// Parametrum retis: pondus (ordo, columna), bias (index), vel scala γ aut translatio β normalizationis strati.
+/// Paramètre du réseau : poids (ligne, colonne), biais (indice), ou échelle γ ou décalage β de la normalisation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Parametrum {
+    Pondus { stratum: usize, ordo: usize, columna: usize },
+    Bias { stratum: usize, index: usize },
+    Scala { stratum: usize, index: usize },
+    Translatio { stratum: usize, index: usize },
+}
+
+/// Relatio verificationis: discrepantia maxima inter gradientem analyticum et numericum.
+/// Rapport de vérification : écart maximal entre gradient analytique et numérique.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificatioGradientum {
+    /// Error maximus |a − n| / max(1, |a| + |n|) per omnia parametra.
+/// Erreur maximale |a − n| / max(1, |a| + |n|) sur tous les paramètres.
+    pub error_maximus: f64,
+
+    /// Parametrum ubi error maximus invenitur.
+/// Paramètre où l’erreur maximale est atteinte.
+    pub parametrum: Parametrum,
+
+    /// Gradiens analyticus (retropropagatio) in eo parametro.
+/// Gradient analytique (rétropropagation) pour ce paramètre.
+    pub analyticus: f64,
+
+    /// Gradiens numericus (differentiae finitae) in eo parametro.
+/// Gradient numérique (différences finies) pour ce paramètre.
+    pub numericus: f64,
+
+    /// Numerus parametrorum verificatorum.
+/// Nombre de paramètres vérifiés.
+    pub parametra: usize,
+}
+
+impl VerificatioGradientum {
+    /// Verum si error maximus intra tolerantiam est.
+/// Vrai si l’erreur maximale est dans la tolérance.
+    pub fn convenit(&self, tolerantia: f64) -> bool {
+        self.error_maximus <= tolerantia
+    }
+}
+
+/// Compara gradientes retropropagationis cum differentiis finitis centratis
+/// (L(θ + h) − L(θ − h)) / 2h pro omni pondere et bias. Computatio in f64 fit,
+/// quamvis rete f32 sit, ne errores rotundationis verificationem obscurent.
+/// Omissio removetur; in modo disciplinae normalizatio per batch statisticas minibatch adhibet.
+/// Compare les gradients de la rétropropagation aux différences finies centrées
+/// (L(θ + h) − L(θ − h)) / 2h pour chaque poids et biais. Le calcul se fait en f64,
+/// même pour un réseau f32, afin que l’arrondi ne masque pas la vérification.
+/// Le dropout est retiré ; en mode entraînement, la normalisation par lot utilise les statistiques du mini-lot.
+pub fn verifica_gradientes<T: Numerus>(
+    rete: &NeuronatusT<T>,
+    intratae: &TensorT2D<T>,
+    exspectatae: &TensorT2D<T>,
+    h: f64,
+) -> VerificatioGradientum {
+    let mut rete: NeuronatusT<f64> = rete.converte();
+    let intratae: TensorT2D<f64> = intratae.converte();
+    let exspectatae: TensorT2D<f64> = exspectatae.converte();
+
+    // `gradientes_minibatch` sine omissione et sine renovatione statisticarum eandem functionem derivat.
+    // `gradientes_minibatch`, sans dropout ni mise à jour des statistiques, dérive la même fonction.
+    let perditio = match rete.modus {
+        Modus::Disciplina => NeuronatusT::perditio_minibatch_disciplinae,
+        Modus::Inferentia => NeuronatusT::perditio_minibatch,
+    };
+    let gradientes = rete.gradientes_minibatch(&intratae, &exspectatae);
+    let mut relatio = VerificatioGradientum {
+        error_maximus: 0.0,
+        parametrum: Parametrum::Bias { stratum: 0, index: 0 },
+        analyticus: 0.0,
+        numericus: 0.0,
+        parametra: 0,
+    };
+
+    // Perditio cum uno parametro mutato; valor pristinus postea restituitur.
+    // Perte avec un seul paramètre modifié ; la valeur d’origine est ensuite restaurée.
+    let gradiens_numericus = |rete: &mut NeuronatusT<f64>, parametrum: Parametrum| {
+        let pristinus = *valor_parametri(rete, parametrum);
+        *valor_parametri(rete, parametrum) = pristinus + h;
+        let plus = perditio(rete, &intratae, &exspectatae);
+        *valor_parametri(rete, parametrum) = pristinus - h;
+        let minus = perditio(rete, &intratae, &exspectatae);
+        *valor_parametri(rete, parametrum) = pristinus;
+        (plus - minus) / (2.0 * h)
+    };
+
+    for (stratum, gradiens) in gradientes.strata.iter().enumerate() {
+        let mut parametra: Vec<(Parametrum, f64)> = Vec::new();
+        for ordo in 0..gradiens.pesi.ordines {
+            for columna in 0..gradiens.pesi.columnae {
+                parametra.push((Parametrum::Pondus { stratum, ordo, columna }, gradiens.pesi[(ordo, columna)]));
+            }
+        }
+        for index in 0..gradiens.bias.magnitudo {
+            parametra.push((Parametrum::Bias { stratum, index }, gradiens.bias.materia[index]));
+        }
+        if let Some(normalizatio) = &gradiens.normalizatio {
+            for index in 0..normalizatio.scala.magnitudo {
+                parametra.push((Parametrum::Scala { stratum, index }, normalizatio.scala.materia[index]));
+                parametra.push((Parametrum::Translatio { stratum, index }, normalizatio.translatio.materia[index]));
+            }
+        }
+
+        for (parametrum, analyticus) in parametra {
+            let numericus = gradiens_numericus(&mut rete, parametrum);
+            let error = (analyticus - numericus).abs() / (analyticus.abs() + numericus.abs()).max(1.0);
+            relatio.parametra += 1;
+            if error >= relatio.error_maximus {
+                relatio = VerificatioGradientum { error_maximus: error, parametrum, analyticus, numericus, ..relatio };
+            }
+        }
+    }
+
+    relatio
+}
+
+/// Referentia mutabilis ad valorem parametri in rete.
+/// Référence mutable vers la valeur du paramètre dans le réseau.
+fn valor_parametri(rete: &mut NeuronatusT<f64>, parametrum: Parametrum) -> &mut f64 {
+    match parametrum {
+        Parametrum::Pondus { stratum, ordo, columna } => &mut rete.strata[stratum].pesi[(ordo, columna)],
+        Parametrum::Bias { stratum, index } => &mut rete.strata[stratum].bias.materia[index],
+        Parametrum::Scala { stratum, index } => &mut normalizatio(rete, stratum).0.materia[index],
+        Parametrum::Translatio { stratum, index } => &mut normalizatio(rete, stratum).1.materia[index],
+    }
+}
+
+/// Scala et translatio normalizationis strati (quae adesse debet).
+/// Échelle et décalage de la normalisation de la couche (qui doit exister).
+fn normalizatio(rete: &mut NeuronatusT<f64>, stratum: usize) -> (&mut TensorT1D<f64>, &mut TensorT1D<f64>) {
+    rete.strata[stratum].normalizatio.as_mut().expect("Stratum sine normalizatione.").parametra_mut()
+}
+
+/// Affichage lisible du rapport de vérification.
+impl fmt::Display for VerificatioGradientum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Error maximus {:.3e} in {:?} (analyticus {:.6e}, numericus {:.6e}; {} parametra)",
+            self.error_maximus, self.parametrum, self.analyticus, self.numericus, self.parametra
+        )
+    }
+}